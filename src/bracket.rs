@@ -5,11 +5,41 @@
 //!
 //! A minima bracket refers to a pair of abscissa `a` and `b` such that both `f(a)` and `f(b)` are larger than
 //! some minima contained between them.
+//!
+//! The signs of function values are only ever compared, never determined from products of function values, so that
+//! functions of extreme magnitude do not overflow or underflow into the wrong branch.
+//!
+//! Tolerances are relative to the magnitude of the bracket. Tolerances below machine precision, including
+//! negative tolerances, refine the bracket to machine precision.
+
+use crate::consts::{GOLDEN_RATIO, GOLDEN_RATIO_CONJUGATE};
+use crate::generic::{constant, Float};
+use crate::{compute_epsilon, interpolation_step, Error, MaybeEval};
+
+/// The error returned by the fallible bracketing methods, such as [`try_bisection`] and [`try_itp`], when the
+/// endpoints do not form a valid bracket. This is the crate-wide [`Error`], under the name used by this module.
+pub use crate::Error as BracketError;
 
-use crate::{compute_epsilon, MaybeEval};
+/// The width of the bracket between `a` and `b`, regardless of their order.
+pub fn bracket_width(a: impl Into<MaybeEval>, b: impl Into<MaybeEval>) -> f64 {
+  (b.into().x() - a.into().x()).abs()
+}
+
+/// Whether the bracket between `a` and `b` already meets the tolerance `tol`, using the same criterion as
+/// [`bisection`], [`brent`], [`ridders`], [`regula_falsi`], [`illinois`], and [`secant_bisection_hybrid`]. A converged
+/// bracket may be used as is, without further refinement.
+///
+/// [`itp`] instead stops once the bracket is at most twice this width.
+pub fn is_converged(a: impl Into<MaybeEval>, b: impl Into<MaybeEval>, tol: f64) -> bool {
+  let (a, b) = (a.into().x(), b.into().x());
+  bracket_width(a, b) <= compute_epsilon(a, b, tol)
+}
 
 /// Locates the root within a bracket using the bisection method.
 /// Requires that `f` is continuous and that `f(a)` and `f(b)` have opposite signs.
+/// Panics if `a > b`.
+///
+/// See [`BisectionIter`] to observe the individual iterations.
 pub fn bisection<F>(f: &F, a: impl Into<MaybeEval>, b: impl Into<MaybeEval>, tol: f64) -> f64
 where
   F: Fn(f64) -> f64,
@@ -21,34 +51,119 @@ where
   }
   assert!(a.x() < b.x());
 
-  let (mut a, fa) = a.evaled(f);
-  let mut b = b.x();
+  let (a, fa) = a.evaled(f);
+  bisect(f, a, fa, b.x(), tol)
+}
 
-  let epsilon = compute_epsilon(a, b, tol);
-  let fa_sign = fa.signum();
+/// Refines the bracket `[a, b]` by bisection, given the value `fa` of `f` at its lower end.
+pub(crate) fn bisect<T, F>(f: &F, a: T, fa: T, b: T, tol: T) -> T
+where
+  T: Float,
+  F: Fn(T) -> T,
+{
+  match BisectionIter::from_bracket(f, MaybeEval::known(a, fa), MaybeEval::unknown(b), tol).last() {
+    Some(BisectionItem::Root(x)) => x,
+    _ => unreachable!("BisectionIter always terminates with a root"),
+  }
+}
 
-  while b - a > epsilon {
-    let x = 0.5 * (a + b);
-    if f(x).signum() == fa_sign {
-      a = x;
-    } else {
-      b = x;
-    }
+/// Locates the root within a bracket using the bisection method, as in [`bisection`], after checking that the
+/// endpoints form a valid bracket.
+///
+/// The endpoints are checked as described in [`try_itp`].
+pub fn try_bisection<F>(
+  f: &F,
+  a: impl Into<MaybeEval>,
+  b: impl Into<MaybeEval>,
+  tol: f64,
+) -> Result<f64, Error>
+where
+  F: Fn(f64) -> f64,
+{
+  let (a, b) = checked_bracket(f, a.into(), b.into())?;
+  Ok(bisection(f, a, b, tol))
+}
+
+/// Checks that the endpoints of a bracket are finite, distinct, and ordered, and that `f` changes sign between them,
+/// returning them with their function values known.
+fn checked_bracket<F>(f: &F, a: MaybeEval, b: MaybeEval) -> Result<(MaybeEval, MaybeEval), Error>
+where
+  F: Fn(f64) -> f64,
+{
+  let (a, b) = (a.finite()?, b.finite()?);
+  if a.x() == b.x() {
+    return Err(Error::ZeroWidth);
+  } else if a.x() > b.x() {
+    return Err(Error::NotOrdered);
   }
 
-  0.5 * (a + b)
+  let a = MaybeEval::from(a.evaled(f)).finite()?;
+  let b = MaybeEval::from(b.evaled(f)).finite()?;
+
+  let (fa, fb) = (a.evaled(f).1, b.evaled(f).1);
+  if (fa > 0.0 && fb > 0.0) || (fa < 0.0 && fb < 0.0) {
+    return Err(Error::SameSign);
+  }
+  Ok((a, b))
 }
 
 /// Locates the root within a bracket using the [ITP method].
 /// Requires that `f` is continuous and that `f(a)` and `f(b)` have opposite signs.
+/// Panics if `a > b`.
 ///
 /// The parameters for the ITP method are set at:
 /// - `k1 = 0.2 / (b - a)`
 /// - `k2 = 2`
 /// - `n0 = 5`
 ///
+/// See [`ItpIter`] to observe the individual iterations.
+///
 /// [ITP Method]: https://dl.acm.org/doi/10.1145/3423597
 pub fn itp<F>(f: &F, a: impl Into<MaybeEval>, b: impl Into<MaybeEval>, tol: f64) -> f64
+where
+  F: Fn(f64) -> f64,
+{
+  match ItpIter::new(f, a, b, tol).last() {
+    Some(ItpItem::Root(x)) => x,
+    _ => unreachable!("ItpIter always terminates with a root"),
+  }
+}
+
+/// Locates the root within a bracket using the ITP method, as in [`itp`], after checking that the endpoints form a
+/// valid bracket.
+///
+/// Returns [`Error::NonFinite`] if either endpoint, or its function value, is NaN or infinite,
+/// [`Error::ZeroWidth`] if the endpoints are equal, [`Error::NotOrdered`] if `a > b`, and [`Error::SameSign`] if
+/// `f(a)` and `f(b)` are both positive or both negative. The endpoints are evaluated if their values are not
+/// already known, and the values are reused by the method.
+pub fn try_itp<F>(
+  f: &F,
+  a: impl Into<MaybeEval>,
+  b: impl Into<MaybeEval>,
+  tol: f64,
+) -> Result<f64, Error>
+where
+  F: Fn(f64) -> f64,
+{
+  let (a, b) = checked_bracket(f, a.into(), b.into())?;
+  Ok(itp(f, a, b, tol))
+}
+
+/// Locates the root within a bracket using [Brent's method], which combines inverse quadratic interpolation and
+/// the secant method with bisection. Requires that `f` is continuous and that `f(a)` and `f(b)` have opposite signs.
+/// Panics if `a > b`.
+///
+/// Interpolation steps are safeguarded in the same way as the parabolic steps of `min::min`, falling back
+/// to bisection whenever they stray from the bracket or fail to shrink quickly enough. Every point evaluated lies
+/// within the bracket, which contains the root at every step, and as in [`bisection`], the midpoint of the final
+/// bracket is returned.
+///
+/// Brent's analysis bounds the iterations by roughly the square of the iterations [`bisection`] takes. The method
+/// gives up after `(n + 1)^2` iterations, where `n` is that count, so that a function which returns NaN or infinity
+/// cannot keep it iterating forever.
+///
+/// [Brent's method]: https://phys.uri.edu/nigh/NumRec/bookfpdf/f9-3.pdf
+pub fn brent<F>(f: &F, a: impl Into<MaybeEval>, b: impl Into<MaybeEval>, tol: f64) -> f64
 where
   F: Fn(f64) -> f64,
 {
@@ -62,60 +177,908 @@ where
   let (mut a, mut fa) = a.evaled(f);
   let (mut b, mut fb) = b.evaled(f);
 
-  let n0 = 5;
-  let k1 = 0.2 / (b - a);
-  let k2 = 2;
   let epsilon = compute_epsilon(a, b, tol);
+  let tol1 = 0.5 * epsilon;
+  let max_iterations = (bisection_iterations(a, b, tol) + 1).pow(2);
+  let mut iterations = 0;
+
+  // b is the best estimate of the root, and c the other end of the bracket around it
+  let (mut c, mut fc) = (b, fb);
+  let mut d = b - a;
+  let mut e = d;
+
+  loop {
+    if (fb > 0.0 && fc > 0.0) || (fb < 0.0 && fc < 0.0) {
+      (c, fc) = (a, fa);
+      d = b - a;
+      e = d;
+    }
+
+    if fc.abs() < fb.abs() {
+      (a, fa) = (b, fb);
+      (b, fb) = (c, fc);
+      (c, fc) = (a, fa);
+    }
+
+    let xm = 0.5 * (c - b);
+    if fb == 0.0 {
+      return b;
+    } else if xm.abs() <= tol1 || iterations == max_iterations {
+      return b + xm;
+    }
+    iterations += 1;
+
+    let step = if e.abs() >= tol1 && fa.abs() > fb.abs() {
+      let s = fb / fa;
+      let (p, q) = if a == c {
+        // Secant method
+        (2.0 * xm * s, 1.0 - s)
+      } else {
+        // Inverse quadratic interpolation
+        let q = fa / fc;
+        let r = fb / fc;
+        (
+          s * (2.0 * xm * q * (q - r) - (b - a) * (r - 1.0)),
+          (q - 1.0) * (r - 1.0) * (s - 1.0),
+        )
+      };
+
+      // The step is p / q; normalize so that q is non-negative
+      let (p, q) = if q < 0.0 { (-p, -q) } else { (p, q) };
+
+      // Stay within three quarters of the way from b to c
+      let reach = (1.5 * xm.abs() - 0.5 * tol1).copysign(xm);
+      interpolation_step(p, q, e, reach.min(0.0), reach.max(0.0))
+    } else {
+      None
+    };
+
+    match step {
+      Some(step) => {
+        e = d;
+        d = step;
+      }
+      None => {
+        d = xm;
+        e = d;
+      }
+    }
+
+    (a, fa) = (b, fb);
+    b += if d.abs() > tol1 { d } else { tol1.copysign(xm) };
+    fb = f(b);
+  }
+}
+
+/// Locates the root within a bracket using [Ridders' method], which fits an exponential through the ends and the
+/// midpoint of the bracket. Requires that `f` is continuous and that `f(a)` and `f(b)` have opposite signs.
+/// Panics if `a > b`.
+///
+/// Each iteration evaluates `f` at the midpoint, halving the bracket, and then at the root of the fitted exponential,
+/// which always lies within the bracket and shrinks it further. The bracket therefore at least halves every two
+/// evaluations, while on smooth functions it converges quadratically, so it typically takes far fewer evaluations
+/// than [`bisection`]. As in [`bisection`], the midpoint of the final bracket is returned.
+///
+/// [Ridders' method]: https://phys.uri.edu/nigh/NumRec/bookfpdf/f9-2.pdf
+pub fn ridders<F>(f: &F, a: impl Into<MaybeEval>, b: impl Into<MaybeEval>, tol: f64) -> f64
+where
+  F: Fn(f64) -> f64,
+{
+  let a = a.into();
+  let b = b.into();
+  if a.x() == b.x() {
+    return a.x();
+  }
+  assert!(a.x() < b.x());
+
+  let (mut a, mut fa) = a.evaled(f);
+  let (mut b, mut fb) = b.evaled(f);
+  if fa == 0.0 {
+    return a;
+  } else if fb == 0.0 {
+    return b;
+  }
+
+  let epsilon = compute_epsilon(a, b, tol);
+
+  while b - a > epsilon {
+    let m = 0.5 * (a + b);
+    let fm = f(m);
+    if fm == 0.0 {
+      return m;
+    }
+
+    // The root of the exponential, written in terms of ratios of the function values so that it cannot overflow.
+    // As fa and fb have opposite signs, the square root is at least one
+    let (ra, rb) = (fa / fm, fb / fm);
+    let shift = (m - a) / (1.0 - ra * rb).sqrt();
+    let x = if (fa > fb) == (fm > 0.0) {
+      m + shift
+    } else {
+      m - shift
+    };
 
-  let n1_2 = (((b - a) / epsilon).log2().ceil() - 1.0).max(0.0) as usize;
-  let n_max = n0 + n1_2;
-  let mut scaled_epsilon = epsilon * 2f64.powi(n_max as i32);
+    if (fm > 0.0) == (fa > 0.0) {
+      (a, fa) = (m, fm);
+    } else {
+      (b, fb) = (m, fm);
+    }
 
-  // The algorithm assumes f(a) <= f(b). If not, we must correct for it
-  let negate = fb < fa;
+    if a < x && x < b {
+      let fx = f(x);
+      if fx == 0.0 {
+        return x;
+      } else if (fx > 0.0) == (fa > 0.0) {
+        (a, fa) = (x, fx);
+      } else {
+        (b, fb) = (x, fx);
+      }
+    }
+  }
 
-  while b - a > 2.0 * epsilon {
-    let x1_2 = 0.5 * (a + b);
-    let r = scaled_epsilon - 0.5 * (b - a);
-    let delta = k1 * (b - a).powi(k2);
+  0.5 * (a + b)
+}
 
-    // Interpolation
-    let xf = (fb * a - fa * b) / (fb - fa);
+/// Locates the root within a bracket using the method of false position, or regula falsi, which steps to the root
+/// of the line through the ends of the bracket. Requires that `f` is continuous and that `f(a)` and `f(b)` have
+/// opposite signs. Panics if `a > b`.
+///
+/// On a function which is convex or concave across the bracket, every step lands on the same side of the root, so
+/// one end of the bracket never moves, and the method converges only linearly, and slowly if that end is far from the
+/// root. The bracket then never becomes narrow, so the method also terminates once successive points are within the
+/// tolerance, or after 1000 steps, returning the last point evaluated. See [`illinois`] for a variant which avoids
+/// this.
+pub fn regula_falsi<F>(f: &F, a: impl Into<MaybeEval>, b: impl Into<MaybeEval>, tol: f64) -> f64
+where
+  F: Fn(f64) -> f64,
+{
+  false_position(f, a.into(), b.into(), tol, false)
+}
+
+/// Locates the root within a bracket using the method of false position, as in [`regula_falsi`], with the
+/// [Illinois modification]. Requires that `f` is continuous and that `f(a)` and `f(b)` have opposite signs.
+/// Panics if `a > b`.
+///
+/// Whenever the same end of the bracket is kept on two consecutive steps, its function value is halved, pulling the
+/// next step towards it. Both ends of the bracket therefore move, and the method converges superlinearly, of order
+/// about 1.44, even where [`regula_falsi`] stalls. The method terminates once the bracket or the distance between
+/// successive points is within the tolerance.
+///
+/// [Illinois modification]: https://doi.org/10.1007/BF01934364
+pub fn illinois<F>(f: &F, a: impl Into<MaybeEval>, b: impl Into<MaybeEval>, tol: f64) -> f64
+where
+  F: Fn(f64) -> f64,
+{
+  false_position(f, a.into(), b.into(), tol, true)
+}
+
+/// The number of steps after which [`regula_falsi`] and [`illinois`] give up, returning the last point evaluated.
+const MAX_FALSE_POSITION_ITERATIONS: usize = 1000;
+
+/// The method of false position, shared by [`regula_falsi`] and, with `illinois` set, [`illinois`].
+fn false_position<F>(f: &F, a: MaybeEval, b: MaybeEval, tol: f64, illinois: bool) -> f64
+where
+  F: Fn(f64) -> f64,
+{
+  if a.x() == b.x() {
+    return a.x();
+  }
+  assert!(a.x() < b.x());
+
+  let (mut a, mut fa) = a.evaled(f);
+  let (mut b, mut fb) = b.evaled(f);
+  if fa == 0.0 {
+    return a;
+  } else if fb == 0.0 {
+    return b;
+  }
+
+  let epsilon = compute_epsilon(a, b, tol);
+
+  // Whether the lower end of the bracket was kept on the previous step, if any step has been taken
+  let mut kept_a = None;
+  let mut x_prev = f64::NAN;
+
+  for _ in 0..MAX_FALSE_POSITION_ITERATIONS {
+    if b - a <= epsilon {
+      return 0.5 * (a + b);
+    }
+
+    // As in the interpolation of the ITP method, written in terms of the ratio of the function values so that it
+    // cannot overflow. Once the bracket is narrow, the point may round onto one of its ends, so bisect instead
+    let mut x = a + (b - a) / (1.0 - fb / fa);
+    if !(a < x && x < b) {
+      x = 0.5 * (a + b);
+    }
+
+    let fx = f(x);
+    if fx == 0.0 {
+      return x;
+    } else if (fx > 0.0) == (fa > 0.0) {
+      (a, fa) = (x, fx);
+      if illinois && kept_a == Some(false) {
+        fb *= 0.5;
+      }
+      kept_a = Some(false);
+    } else {
+      (b, fb) = (x, fx);
+      if illinois && kept_a == Some(true) {
+        fa *= 0.5;
+      }
+      kept_a = Some(true);
+    }
+
+    if (x - x_prev).abs() <= epsilon {
+      return x;
+    }
+    x_prev = x;
+  }
+
+  x_prev
+}
+
+/// Locates the root within a bracket by taking secant steps, falling back to bisection when they are unhelpful.
+/// Requires that `f` is continuous and that `f(a)` and `f(b)` have opposite signs.
+/// Panics if `a > b`.
+///
+/// A secant step is taken whenever the secant point lies within the bracket, unless the previous secant step failed
+/// to halve the bracket, in which case a bisection step is taken instead. The bracket therefore at least halves
+/// every two iterations, and the method is capped at twice the iterations [`bisection`] would take. It is simpler than
+/// [`itp`] or [`brent`], at the cost of converging more slowly on functions which are far from linear.
+pub fn secant_bisection_hybrid<F>(
+  f: &F,
+  a: impl Into<MaybeEval>,
+  b: impl Into<MaybeEval>,
+  tol: f64,
+) -> f64
+where
+  F: Fn(f64) -> f64,
+{
+  let a = a.into();
+  let b = b.into();
+  if a.x() == b.x() {
+    return a.x();
+  }
+  assert!(a.x() < b.x());
+
+  let (mut a, mut fa) = a.evaled(f);
+  let (mut b, mut fb) = b.evaled(f);
+
+  let epsilon = compute_epsilon(a, b, tol);
+  let max_iterations = 2 * bisection_iterations(a, b, tol);
+  let mut bisect = false;
+
+  for _ in 0..max_iterations {
+    let width = b - a;
+    if width <= epsilon {
+      break;
+    }
+
+    // As in ITP, the ratio of the function values avoids overflow
+    let secant = a + width / (1.0 - fb / fa);
+    let x = if !bisect && a < secant && secant < b {
+      // Keep the point far enough from the ends that the bracket shrinks
+      secant.clamp(a + 0.25 * epsilon, b - 0.25 * epsilon)
+    } else {
+      0.5 * (a + b)
+    };
+
+    let fx = f(x);
+    if fx == 0.0 {
+      return x;
+    } else if (fx > 0.0) == (fa > 0.0) {
+      (a, fa) = (x, fx);
+    } else {
+      (b, fb) = (x, fx);
+    }
+
+    bisect = !bisect && b - a > 0.5 * width;
+  }
+
+  0.5 * (a + b)
+}
+
+/// Locates the root within a bracket of a function whose evaluations are noisy, such as measurements or simulation
+/// results, using bisection on averaged evaluations.
+/// Requires that the mean of `f` is continuous and has opposite signs at `a` and `b`.
+/// Panics if `a > b` or if `samples` is zero.
+///
+/// `noise_level` is the standard deviation of the noise in a single evaluation. Each point is evaluated `samples`
+/// times and the results are averaged, reducing the standard deviation to `sigma = noise_level / sqrt(samples)`.
+/// The sign of an average is only trusted if it exceeds `3 sigma` in magnitude, so refinement stops, returning the
+/// midpoint, as soon as a midpoint lies in this band, as well as once the bracket converges to `tol`. The achievable
+/// precision is therefore about `3 sigma / |f'(x)|` about the root `x`: halving it requires four times the samples.
+/// Refining further would only follow the noise, so a bracket far narrower than this is no more accurate.
+pub fn robust_root<F>(f: &F, a: f64, b: f64, noise_level: f64, samples: usize, tol: f64) -> f64
+where
+  F: Fn(f64) -> f64,
+{
+  assert!(a <= b);
+  assert!(samples > 0);
+
+  let mean = |x: f64| (0..samples).map(|_| f(x)).sum::<f64>() / samples as f64;
+  let threshold = 3.0 * noise_level / (samples as f64).sqrt();
+
+  let (mut a, mut b) = (a, b);
+  let fa_positive = mean(a) > 0.0;
+  let epsilon = compute_epsilon(a, b, tol);
+
+  while b - a > epsilon {
+    let x = 0.5 * (a + b);
+    let fx = mean(x);
+    if fx.abs() <= threshold {
+      return x;
+    }
+
+    if (fx > 0.0) == fa_positive {
+      a = x;
+    } else {
+      b = x;
+    }
+  }
+
+  0.5 * (a + b)
+}
+
+/// Returns the number of iterations, each evaluating `f` once, which [`bisection`] takes to refine the bracket
+/// `[a, b]` to the given tolerance. This count is exact, and does not include the initial evaluation of `f(a)`.
+pub fn bisection_iterations(a: f64, b: f64, tol: f64) -> usize {
+  let epsilon = compute_epsilon(a, b, tol);
+
+  let mut width = (b - a).abs();
+  let mut iterations = 0;
+  while width > epsilon {
+    width *= 0.5;
+    iterations += 1;
+  }
+
+  iterations
+}
+
+/// Returns an upper bound on the number of iterations, each evaluating `f` once, which [`itp`] takes to refine the
+/// bracket `[a, b]` to the given tolerance. This does not include the evaluations of the endpoints.
+///
+/// The bound exceeds the number of iterations [`bisection`] takes by at most `n0 = 5`, and is frequently far
+/// larger than the number of iterations actually taken on smooth functions.
+pub fn itp_iterations(a: f64, b: f64, tol: f64) -> usize {
+  let epsilon = compute_epsilon(a, b, tol);
+
+  // In exact arithmetic the method takes at most n_max iterations, but the final bracket may be wider than the
+  // threshold by a rounding error, which costs one more
+  itp_n_max(a, b, epsilon) + 1
+}
+
+/// The `n_max` parameter of the ITP method: the number of iterations it takes in exact arithmetic in the worst case.
+fn itp_n_max<T: Float>(a: T, b: T, epsilon: T) -> usize {
+  let n0 = 5;
+  let n1_2 = (((b - a).abs() / epsilon).log2().ceil() - T::one()).max(T::zero());
+  n0 + n1_2.to_usize().unwrap_or(0)
+}
+
+/// The reliability characteristics of a [`BracketSolver`], so that a method may be chosen programmatically.
+#[derive(Debug, Clone, Copy)]
+pub struct Guarantees {
+  /// Whether the method keeps the root bracketed throughout, so that it converges to a root within the bracket
+  /// whenever `f` is continuous and changes sign across it.
+  pub bracketing: bool,
+  /// A bound on the number of iterations, each evaluating `f` once, the method takes to refine the bracket `[a, b]`
+  /// to the tolerance `tol`, given as `(a, b, tol)`. This does not include the evaluations of the endpoints. `None`
+  /// if the method has no such bound.
+  pub worst_case_iterations: Option<fn(f64, f64, f64) -> usize>,
+}
+
+/// A method which locates the root within a bracket, such as the functions of this module.
+pub trait BracketSolver {
+  /// Locates the root within the bracket `[a, b]`, as in the corresponding function of this module.
+  fn solve<F>(&self, f: &F, a: impl Into<MaybeEval>, b: impl Into<MaybeEval>, tol: f64) -> f64
+  where
+    F: Fn(f64) -> f64;
+
+  /// The reliability characteristics of the method.
+  fn guarantees() -> Guarantees;
+}
+
+/// The [`bisection`] method, as a [`BracketSolver`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Bisection;
+
+impl BracketSolver for Bisection {
+  fn solve<F>(&self, f: &F, a: impl Into<MaybeEval>, b: impl Into<MaybeEval>, tol: f64) -> f64
+  where
+    F: Fn(f64) -> f64,
+  {
+    bisection(f, a, b, tol)
+  }
+
+  fn guarantees() -> Guarantees {
+    Guarantees {
+      bracketing: true,
+      worst_case_iterations: Some(bisection_iterations),
+    }
+  }
+}
+
+/// The [`itp`] method, as a [`BracketSolver`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Itp;
+
+impl BracketSolver for Itp {
+  fn solve<F>(&self, f: &F, a: impl Into<MaybeEval>, b: impl Into<MaybeEval>, tol: f64) -> f64
+  where
+    F: Fn(f64) -> f64,
+  {
+    itp(f, a, b, tol)
+  }
+
+  fn guarantees() -> Guarantees {
+    Guarantees {
+      bracketing: true,
+      worst_case_iterations: Some(itp_iterations),
+    }
+  }
+}
+
+/// The [`brent`] method, as a [`BracketSolver`].
+///
+/// Brent's analysis bounds the iterations of his original algorithm by roughly the square of the iterations
+/// [`bisection`] takes, but this bound is not established for this implementation, so none is given.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Brent;
+
+impl BracketSolver for Brent {
+  fn solve<F>(&self, f: &F, a: impl Into<MaybeEval>, b: impl Into<MaybeEval>, tol: f64) -> f64
+  where
+    F: Fn(f64) -> f64,
+  {
+    brent(f, a, b, tol)
+  }
+
+  fn guarantees() -> Guarantees {
+    Guarantees {
+      bracketing: true,
+      worst_case_iterations: None,
+    }
+  }
+}
+
+/// The [`ridders`] method, as a [`BracketSolver`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Ridders;
+
+impl BracketSolver for Ridders {
+  fn solve<F>(&self, f: &F, a: impl Into<MaybeEval>, b: impl Into<MaybeEval>, tol: f64) -> f64
+  where
+    F: Fn(f64) -> f64,
+  {
+    ridders(f, a, b, tol)
+  }
+
+  fn guarantees() -> Guarantees {
+    Guarantees {
+      bracketing: true,
+      worst_case_iterations: Some(|a, b, tol| 2 * bisection_iterations(a, b, tol)),
+    }
+  }
+}
+
+/// The [`secant_bisection_hybrid`] method, as a [`BracketSolver`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SecantBisectionHybrid;
+
+impl BracketSolver for SecantBisectionHybrid {
+  fn solve<F>(&self, f: &F, a: impl Into<MaybeEval>, b: impl Into<MaybeEval>, tol: f64) -> f64
+  where
+    F: Fn(f64) -> f64,
+  {
+    secant_bisection_hybrid(f, a, b, tol)
+  }
+
+  fn guarantees() -> Guarantees {
+    Guarantees {
+      bracketing: true,
+      worst_case_iterations: Some(|a, b, tol| 2 * bisection_iterations(a, b, tol)),
+    }
+  }
+}
+
+/// The [`regula_falsi`] method, as a [`BracketSolver`].
+///
+/// The method may converge arbitrarily slowly where one end of the bracket never moves, so no bound is given.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RegulaFalsi;
+
+impl BracketSolver for RegulaFalsi {
+  fn solve<F>(&self, f: &F, a: impl Into<MaybeEval>, b: impl Into<MaybeEval>, tol: f64) -> f64
+  where
+    F: Fn(f64) -> f64,
+  {
+    regula_falsi(f, a, b, tol)
+  }
+
+  fn guarantees() -> Guarantees {
+    Guarantees {
+      bracketing: true,
+      worst_case_iterations: None,
+    }
+  }
+}
+
+/// The [`illinois`] method, as a [`BracketSolver`].
+///
+/// Halving the function value of a kept end does not bound how little a step may shrink the bracket, so no bound is
+/// given.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Illinois;
+
+impl BracketSolver for Illinois {
+  fn solve<F>(&self, f: &F, a: impl Into<MaybeEval>, b: impl Into<MaybeEval>, tol: f64) -> f64
+  where
+    F: Fn(f64) -> f64,
+  {
+    illinois(f, a, b, tol)
+  }
+
+  fn guarantees() -> Guarantees {
+    Guarantees {
+      bracketing: true,
+      worst_case_iterations: None,
+    }
+  }
+}
+
+/// The sub-step of the ITP method which selected the point evaluated during an iteration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ItpStep {
+  /// The interpolated point, truncated towards the midpoint, was used.
+  Truncation,
+  /// The truncation reached the midpoint, or the selected point rounded onto an end of the bracket, so a plain
+  /// bisection step was taken.
+  Bisection,
+  /// The truncated point fell outside the minmax interval and was projected onto it.
+  Projection,
+}
+
+/// An item yielded by [`ItpIter`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ItpItem<T = f64> {
+  /// The bracket after an iteration, along with the sub-step that selected the evaluated point.
+  Bracket {
+    /// The lower end of the bracket.
+    a: T,
+    /// The upper end of the bracket.
+    b: T,
+    /// The sub-step taken during the iteration.
+    step: ItpStep,
+  },
+  /// The refined root. This is always the final item.
+  Root(T),
+}
+
+/// The state of an in-progress ITP solve, independent of the function being solved.
+///
+/// The state may be extracted from an [`ItpIter`] with [`ItpIter::state`], persisted (with the `serde` feature), and
+/// later resumed with [`ItpIter::resume`], which continues exactly where the original iterator left off.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ItpState<T = f64> {
+  a: T,
+  fa: T,
+  b: T,
+  fb: T,
+  k1: T,
+  k2: i32,
+  epsilon: T,
+  scaled_epsilon: T,
+  negate: bool,
+  done: bool,
+}
+
+/// An iterator over the successive brackets produced by the [ITP method], terminating with the refined root.
+/// Requires that `f` is continuous and that `f(a)` and `f(b)` have opposite signs.
+///
+/// [ITP Method]: https://dl.acm.org/doi/10.1145/3423597
+pub struct ItpIter<'a, F, T = f64> {
+  f: &'a F,
+  state: ItpState<T>,
+}
+
+impl<'a, F> ItpIter<'a, F>
+where
+  F: Fn(f64) -> f64,
+{
+  /// Begins the ITP method on the bracket `[a, b]`, evaluating the endpoints if they are not already known.
+  /// A bracket which is already within tolerance, including one of zero width, yields its midpoint as the root
+  /// without evaluating `f`.
+  /// Panics if `a > b`.
+  pub fn new(f: &'a F, a: impl Into<MaybeEval>, b: impl Into<MaybeEval>, tol: f64) -> Self {
+    let a = a.into();
+    let b = b.into();
+    assert!(a.x() <= b.x());
+
+    Self::from_bracket(f, a, b, tol)
+  }
+
+  /// Resumes the ITP method from a previously extracted state. `f` must be the function the state was created with.
+  pub fn resume(f: &'a F, state: ItpState) -> Self {
+    Self { f, state }
+  }
+
+  /// The current state of the solve, which may be resumed with [`ItpIter::resume`].
+  pub fn state(&self) -> ItpState {
+    self.state
+  }
+}
+
+impl<'a, F, T> ItpIter<'a, F, T>
+where
+  T: Float,
+  F: Fn(T) -> T,
+{
+  /// Begins the ITP method on the bracket `[a, b]`, given each end along with its value, if known, as in
+  /// [`ItpIter::new`]. Requires that `a <= b`.
+  pub(crate) fn from_bracket(f: &'a F, a: MaybeEval<T>, b: MaybeEval<T>, tol: T) -> Self {
+    let (a, fa, b, fb) = (a.x(), a.fx(), b.x(), b.fx());
+    let two = constant::<T>(2.0);
+
+    // A bracket already within tolerance needs no evaluations. For a very narrow bracket, k1 = 0.2 / (b - a) would
+    // also be enormous. The function values are never used, and are zero rather than NaN so that the state can be
+    // serialized
+    let epsilon = compute_epsilon(a, b, tol);
+    if b - a <= two * epsilon {
+      let state = ItpState {
+        a,
+        fa: T::zero(),
+        b,
+        fb: T::zero(),
+        k1: T::zero(),
+        k2: 2,
+        epsilon,
+        scaled_epsilon: T::zero(),
+        negate: false,
+        done: false,
+      };
+      return Self { f, state };
+    }
+
+    let fa = fa.unwrap_or_else(|| f(a));
+    let fb = fb.unwrap_or_else(|| f(b));
+
+    let k1 = constant::<T>(0.2) / (b - a);
+    let k2 = 2;
+
+    let n_max = itp_n_max(a, b, epsilon);
+    let scaled_epsilon = epsilon * two.powi(n_max as i32);
+
+    // The algorithm assumes f(a) <= f(b). If not, we must correct for it
+    let negate = fb < fa;
+
+    let state = ItpState {
+      a,
+      fa,
+      b,
+      fb,
+      k1,
+      k2,
+      epsilon,
+      scaled_epsilon,
+      negate,
+      done: false,
+    };
+    Self { f, state }
+  }
+}
+
+impl<F, T> Iterator for ItpIter<'_, F, T>
+where
+  T: Float,
+  F: Fn(T) -> T,
+{
+  type Item = ItpItem<T>;
+
+  fn next(&mut self) -> Option<ItpItem<T>> {
+    let half = constant::<T>(0.5);
+    let state = &mut self.state;
+    if state.done {
+      return None;
+    }
+
+    let (a, fa, b, fb) = (state.a, state.fa, state.b, state.fb);
+
+    if b - a <= constant::<T>(2.0) * state.epsilon {
+      state.done = true;
+      return Some(ItpItem::Root(half * (a + b)));
+    }
+
+    let x1_2 = half * (a + b);
+    let r = state.scaled_epsilon - half * (b - a);
+    let delta = state.k1 * (b - a).powi(state.k2);
+
+    // Interpolation. This is written in terms of the ratio of the function values, rather than their products or
+    // difference, so that it cannot overflow or underflow for extreme magnitudes of f. As fa and fb have opposite
+    // signs, the denominator is at least one.
+    let xf = a + (b - a) / (T::one() - fb / fa);
 
     // Truncation
     let sigma = x1_2 - xf;
-    let xt = if delta <= (x1_2 - xf).abs() {
-      xf + delta.copysign(sigma)
+    let (xt, mut step) = if delta <= (x1_2 - xf).abs() {
+      (xf + delta.copysign(sigma), ItpStep::Truncation)
     } else {
-      x1_2
+      (x1_2, ItpStep::Bisection)
     };
 
     // Projection
-    let x_itp = if (xt - x1_2).abs() <= r {
+    let mut x_itp = if (xt - x1_2).abs() <= r {
       xt
     } else {
+      step = ItpStep::Projection;
       x1_2 - r.copysign(sigma)
     };
 
+    // Once the truncation is below the resolution of x, the point may round onto an end of the bracket, which would
+    // then not shrink. Bisect instead, to guarantee progress
+    if x_itp <= a || x_itp >= b {
+      x_itp = x1_2;
+      step = ItpStep::Bisection;
+    }
+
     // Update interval
-    let f_itp = f(x_itp);
+    let f_itp = (self.f)(x_itp);
 
-    if f_itp == 0.0 {
-      return x_itp;
-    } else if negate ^ (f_itp > 0.0) {
-      (b, fb) = (x_itp, f_itp);
+    if f_itp == T::zero() {
+      state.done = true;
+      return Some(ItpItem::Root(x_itp));
+    } else if state.negate ^ (f_itp > T::zero()) {
+      (state.b, state.fb) = (x_itp, f_itp);
     } else {
-      (a, fa) = (x_itp, f_itp);
+      (state.a, state.fa) = (x_itp, f_itp);
     }
 
-    scaled_epsilon *= 0.5;
+    state.scaled_epsilon = state.scaled_epsilon * half;
+
+    Some(ItpItem::Bracket {
+      a: state.a,
+      b: state.b,
+      step,
+    })
   }
+}
 
-  0.5 * (a + b)
+/// An item yielded by [`BisectionIter`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BisectionItem<T = f64> {
+  /// The bracket after an iteration.
+  Bracket {
+    /// The lower end of the bracket.
+    a: T,
+    /// The upper end of the bracket.
+    b: T,
+  },
+  /// The refined root. This is always the final item.
+  Root(T),
+}
+
+/// The state of an in-progress bisection, independent of the function being solved.
+///
+/// The state may be extracted from a [`BisectionIter`] with [`BisectionIter::state`], persisted (with the `serde`
+/// feature), and later resumed with [`BisectionIter::resume`], which continues exactly where the original iterator
+/// left off.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BisectionState<T = f64> {
+  a: T,
+  b: T,
+  fa_sign: T,
+  epsilon: T,
+  done: bool,
+}
+
+/// An iterator over the successive brackets produced by the bisection method, terminating with the refined root.
+/// Requires that `f` is continuous and that `f(a)` and `f(b)` have opposite signs.
+pub struct BisectionIter<'a, F, T = f64> {
+  f: &'a F,
+  state: BisectionState<T>,
+}
+
+impl<'a, F> BisectionIter<'a, F>
+where
+  F: Fn(f64) -> f64,
+{
+  /// Begins the bisection method on the bracket `[a, b]`, evaluating `f(a)` if it is not already known.
+  /// A bracket which is already within tolerance, including one of zero width, yields its midpoint as the root
+  /// without evaluating `f`.
+  /// Panics if `a > b`.
+  pub fn new(f: &'a F, a: impl Into<MaybeEval>, b: impl Into<MaybeEval>, tol: f64) -> Self {
+    let a = a.into();
+    let b = b.into();
+    assert!(a.x() <= b.x());
+
+    Self::from_bracket(f, a, b, tol)
+  }
+
+  /// Resumes the bisection method from a previously extracted state. `f` must be the function the state was created
+  /// with.
+  pub fn resume(f: &'a F, state: BisectionState) -> Self {
+    Self { f, state }
+  }
+
+  /// The current state of the solve, which may be resumed with [`BisectionIter::resume`].
+  pub fn state(&self) -> BisectionState {
+    self.state
+  }
+}
+
+impl<'a, F, T> BisectionIter<'a, F, T>
+where
+  T: Float,
+  F: Fn(T) -> T,
+{
+  /// Begins the bisection method on the bracket `[a, b]`, given each end along with its value, if known, as in
+  /// [`BisectionIter::new`]. Requires that `a <= b`.
+  pub(crate) fn from_bracket(f: &'a F, a: MaybeEval<T>, b: MaybeEval<T>, tol: T) -> Self {
+    // A bracket already within tolerance needs no evaluations. The sign is then never used, and is zero rather than
+    // NaN so that the state can be serialized
+    let epsilon = compute_epsilon(a.x(), b.x(), tol);
+    let fa_sign = if b.x() - a.x() > epsilon {
+      a.fx().unwrap_or_else(|| f(a.x())).signum()
+    } else {
+      T::zero()
+    };
+
+    let state = BisectionState {
+      a: a.x(),
+      b: b.x(),
+      fa_sign,
+      epsilon,
+      done: false,
+    };
+    Self { f, state }
+  }
+}
+
+impl<F, T> Iterator for BisectionIter<'_, F, T>
+where
+  T: Float,
+  F: Fn(T) -> T,
+{
+  type Item = BisectionItem<T>;
+
+  fn next(&mut self) -> Option<BisectionItem<T>> {
+    let half = constant::<T>(0.5);
+    let state = &mut self.state;
+    if state.done {
+      return None;
+    }
+
+    if state.b - state.a <= state.epsilon {
+      state.done = true;
+      return Some(BisectionItem::Root(half * (state.a + state.b)));
+    }
+
+    let x = half * (state.a + state.b);
+    if (self.f)(x).signum() == state.fa_sign {
+      state.a = x;
+    } else {
+      state.b = x;
+    }
+
+    Some(BisectionItem::Bracket {
+      a: state.a,
+      b: state.b,
+    })
+  }
 }
 
 /// Determines a bracket around a minimum of the given function by first evaluating at `x` and then searching in the direction of `step` with successively doubling step sizes.
 /// Assumes `f(x)` is positive, `f` decreases in the direction of `step`, and that we're looking for a minimum.
+///
+/// These assumptions are preconditions the caller must uphold; the sign of `f(x)` is only checked in debug builds.
+///
+/// If `f(x)` is exactly zero, `x` is already a minimum of the non-negative function, and the degenerate bracket
+/// `(x, x)` is returned without evaluating any other points.
+///
+/// Returns `None` if the search passes `min_x` or `max_x`, or overflows, without finding a bracket, as happens when
+/// `f` decreases throughout. The search also gives up after enough doublings for even the smallest step to overflow,
+/// so that a zero or NaN `step` cannot loop forever.
 pub fn find_bracket<F>(
   f: &F,
   x: impl Into<MaybeEval>,
@@ -129,24 +1092,27 @@ where
   // Exponentially step along the path until we find a bracket
   // step is in the downhill direction
   let (mut a, mut fa) = x.into().evaled(f);
-  assert!(fa >= 0.0);
+  debug_assert!(fa >= 0.0);
+
+  if fa == 0.0 {
+    return Some((MaybeEval::known(a, fa), MaybeEval::known(a, fa)));
+  }
 
   let mut b = a;
   let mut fb;
 
-  // TODO: Not forever
-  loop {
+  for _ in 0..MAX_DOUBLINGS {
     b += step;
     fb = f(b);
 
-    // We've explored up to the boundary without finding a bracket
-    if b < min_x || b > max_x {
+    // We've explored up to the boundary, or past the largest float, without finding a bracket
+    if b < min_x || b > max_x || !b.is_finite() {
       return None;
     }
 
     if fb > fa {
       a -= 0.5 * step;
-      return Some((a.into(), (b, fb).into()));
+      return Some((MaybeEval::unknown(a), MaybeEval::known(b, fb)));
     }
 
     a = b;
@@ -154,33 +1120,320 @@ where
 
     step *= 2.0;
   }
+
+  None
+}
+
+/// The number of doublings after which [`find_bracket`] gives up: enough for a step as small as the smallest
+/// subnormal float to overflow.
+const MAX_DOUBLINGS: usize = 2100;
+
+/// Determines a bracket around a minimum of the given function, starting from the points `a` and `b`, using the
+/// `mnbrak` routine described in this [paper]. Returns a triplet `(a, b, c)` with `a < b < c` and `f(b)` no larger
+/// than `f(a)` or `f(c)`.
+///
+/// The search proceeds downhill from the higher of `f(a)` and `f(b)`. Each step jumps to the vertex of the parabola
+/// through the last three points, up to a limit of 100 times the last step, and otherwise magnifies the last step by
+/// the golden ratio. On smooth functions this reaches a bracket in fewer evaluations than the doubling steps of
+/// [`find_bracket`], and unlike [`find_bracket`] there is no requirement on the sign of `f`.
+///
+/// If `f` decreases without bound in the search direction, the search ends once it overflows, and the returned
+/// triplet is not a bracket.
+///
+/// [paper]: https://phys.uri.edu/nigh/NumRec/bookfpdf/f10-1.pdf
+pub fn bracket_min_parabolic<F>(f: &F, a: f64, b: f64) -> (f64, f64, f64)
+where
+  F: Fn(f64) -> f64,
+{
+  const LIMIT: f64 = 100.0;
+  const TINY: f64 = 1e-20;
+
+  let (mut a, mut fa) = (a, f(a));
+  let (mut b, mut fb) = (b, f(b));
+  if fb > fa {
+    (a, b) = (b, a);
+    (fa, fb) = (fb, fa);
+  }
+
+  let mut c = b + GOLDEN_RATIO * (b - a);
+  let mut fc = f(c);
+
+  while fb > fc && c.is_finite() {
+    // The vertex of the parabola through a, b, and c. When the points are collinear, the denominator is kept from
+    // vanishing, and the vertex lies far away, beyond the limit to which it is then clamped.
+    let r = (b - a) * (fb - fc);
+    let q = (b - c) * (fb - fa);
+    let mut u = b - ((b - c) * q - (b - a) * r) / (2.0 * (q - r).abs().max(TINY).copysign(q - r));
+    let u_lim = b + LIMIT * (c - b);
+    let mut fu;
+
+    if (b - u) * (u - c) > 0.0 {
+      // The vertex lies between b and c
+      fu = f(u);
+      if fu < fc {
+        (a, b, c) = (b, u, c);
+        break;
+      } else if fu > fb {
+        c = u;
+        break;
+      }
+      u = c + GOLDEN_RATIO * (c - b);
+      fu = f(u);
+    } else if (c - u) * (u - u_lim) > 0.0 {
+      // The vertex lies beyond c, within the limit
+      fu = f(u);
+      if fu < fc {
+        (b, c) = (c, u);
+        (fb, fc) = (fc, fu);
+        u = c + GOLDEN_RATIO * (c - b);
+        fu = f(u);
+      }
+    } else if (u - u_lim) * (u_lim - c) >= 0.0 {
+      u = u_lim;
+      fu = f(u);
+    } else {
+      u = c + GOLDEN_RATIO * (c - b);
+      fu = f(u);
+    }
+
+    (a, b, c) = (b, c, u);
+    (fa, fb, fc) = (fb, fc, fu);
+  }
+
+  if a < c {
+    (a, b, c)
+  } else {
+    (c, b, a)
+  }
 }
 
 /// Determines a bracket around a root of the given function by first evaluating at `x`
 /// and then searching in the direction of `step` with successively doubling step sizes.
+///
+/// Only the signs at the ends of each step are compared, so a step which passes over an even number of roots does
+/// not detect them. As the step doubles, closely-spaced roots far from `x` are increasingly likely to be missed.
+/// See [`find_root_bracket_with_max_step`] to limit the step size.
 pub fn find_root_bracket<F>(
   f: &F,
   x: impl Into<MaybeEval>,
-  mut step: f64,
+  step: f64,
+) -> Option<(MaybeEval, MaybeEval)>
+where
+  F: Fn(f64) -> f64,
+{
+  find_root_bracket_with_max_step(f, x, step, f64::INFINITY)
+}
+
+/// Determines a bracket around a root of the given function as in [`find_root_bracket`], but never takes a step
+/// larger in magnitude than `max_step`.
+///
+/// Roots separated by more than `max_step` cannot be stepped over together, so the first root found is never
+/// preceded by a missed pair of roots further apart than that. Once the step reaches `max_step`, the search advances
+/// linearly rather than exponentially, so it may take many evaluations to find a distant root, or to give up when
+/// there is none.
+/// Panics if `max_step` is negative or NaN.
+pub fn find_root_bracket_with_max_step<F>(
+  f: &F,
+  x: impl Into<MaybeEval>,
+  step: f64,
+  max_step: f64,
+) -> Option<(MaybeEval, MaybeEval)>
+where
+  F: Fn(f64) -> f64,
+{
+  find_root_bracket_with_max_extent(f, x, step, max_step, f64::INFINITY)
+}
+
+/// Determines a bracket around a root of the given function as in [`find_root_bracket_with_max_step`], but never
+/// searches further than `max_extent` from `x`. The last step is shortened to end exactly at that distance.
+///
+/// Returns `None` if no sign change is found within the extent. Regardless of the extent, the search also gives up,
+/// rather than returning a bracket which does not contain a root, once `f` is NaN, including at `x` itself, if `x` is
+/// not finite, once the searched point overflows, or once a step is lost to rounding, such as a zero step, or a step
+/// of `max_step` far from the origin.
+/// Panics if `max_step` is negative or NaN.
+pub fn find_root_bracket_with_max_extent<F>(
+  f: &F,
+  x: impl Into<MaybeEval>,
+  step: f64,
+  max_step: f64,
+  max_extent: f64,
 ) -> Option<(MaybeEval, MaybeEval)>
 where
   F: Fn(f64) -> f64,
 {
-  let (mut x, mut fx) = x.into().evaled(f);
-  let sign = fx.signum();
+  assert!(max_step >= 0.0);
+
+  let (x0, fx0) = x.into().evaled(f);
+  // A NaN sign would differ from that of every point searched
+  if !x0.is_finite() || fx0.is_nan() {
+    return None;
+  }
+
+  let sign = fx0.signum();
+  let mut step = step.clamp(-max_step, max_step);
+  let limit = x0 + max_extent.copysign(step);
+
+  let (mut x, mut fx) = (x0, fx0);
+  loop {
+    let mut new_x = x + step;
+    if (new_x - x0).abs() > max_extent {
+      new_x = limit;
+    }
+
+    if new_x == x || !new_x.is_finite() {
+      return None;
+    }
 
-  while x.is_finite() {
-    let new_x = x + step;
     let new_fx = f(new_x);
+    if new_fx.is_nan() {
+      return None;
+    }
 
     if new_fx.signum() != sign {
-      return Some(((x, fx).into(), (new_x, new_fx).into()));
+      return Some((MaybeEval::known(x, fx), MaybeEval::known(new_x, new_fx)));
     }
 
     x = new_x;
     fx = new_fx;
 
-    step *= 2.0;
+    step = (2.0 * step).clamp(-max_step, max_step);
+  }
+}
+
+/// Locates the first root to the right of `x0`, stepping right by `step` until `f` changes sign, and then refining
+/// the root within that step with [`itp`]. Returns `None` if there is no sign change before `max_x`.
+/// Panics if `step` is not positive.
+///
+/// If `x0` is itself a root, it is returned without stepping. To continue scanning past a root, search again from
+/// just beyond it. As in [`find_root_bracket`], a step which passes over an even number of roots does not detect
+/// them, so `step` should be smaller than the spacing of the roots of interest.
+pub fn next_root_right<F>(f: &F, x0: f64, step: f64, max_x: f64, tol: f64) -> Option<f64>
+where
+  F: Fn(f64) -> f64,
+{
+  assert!(step > 0.0);
+  next_root(f, x0, step, max_x, tol)
+}
+
+/// Locates the first root to the left of `x0`, as in [`next_root_right`], stepping left by `step` down to `min_x`.
+/// Panics if `step` is not positive.
+pub fn next_root_left<F>(f: &F, x0: f64, step: f64, min_x: f64, tol: f64) -> Option<f64>
+where
+  F: Fn(f64) -> f64,
+{
+  assert!(step > 0.0);
+  next_root(f, x0, -step, min_x, tol)
+}
+
+/// Returns the brackets of the roots of `f` on `[a, b]`, found by sampling `n + 1` equally spaced points and keeping
+/// each consecutive pair across which `f` changes sign. Each bracket may then be refined with, say, [`itp`].
+/// Panics if `a > b`.
+///
+/// `f` is evaluated exactly once at each point. A point where `f` is exactly zero closes the bracket which ends there,
+/// or opens the first bracket if it is `a`, so that each such root is reported once. As in [`next_root_right`], a
+/// pair which spans an even number of roots does not detect them, so `n` should be large enough that the points are
+/// more closely spaced than the roots of interest.
+///
+/// Requires the `std` feature.
+#[cfg(feature = "std")]
+pub fn find_all_brackets<F>(f: &F, a: f64, b: f64, n: usize) -> Vec<(f64, f64)>
+where
+  F: Fn(f64) -> f64,
+{
+  assert!(a <= b);
+
+  let mut brackets = Vec::new();
+  let (mut x, mut fx) = (a, f(a));
+
+  for i in 1..=n {
+    // Each point is computed from a so errors do not accumulate, and the last is exactly b
+    let next = if i == n {
+      b
+    } else {
+      a + (b - a) * (i as f64 / n as f64)
+    };
+    let f_next = f(next);
+
+    let crosses = (fx < 0.0 && f_next > 0.0) || (fx > 0.0 && f_next < 0.0);
+    if crosses || f_next == 0.0 || (i == 1 && fx == 0.0) {
+      brackets.push((x, next));
+    }
+
+    (x, fx) = (next, f_next);
+  }
+
+  brackets
+}
+
+/// The number of intervals into which [`find_roots`] divides its range when scanning for sign changes.
+#[cfg(feature = "std")]
+const FIND_ROOTS_INTERVALS: usize = 1000;
+
+/// Returns the roots of `f` on `[a, b]` in ascending order, found by scanning for brackets with
+/// [`find_all_brackets`] at 1000 intervals and refining each to full precision with [`itp`].
+/// Panics if `a > b`.
+///
+/// Roots within a millionth of an interval of each other, such as those of a noisy function near a root, are reported
+/// once. As with [`find_all_brackets`], roots of even multiplicity, and pairs of roots closer than an interval, may
+/// be missed; call that function with a finer resolution and refine the brackets directly if that matters.
+///
+/// Requires the `std` feature.
+#[cfg(feature = "std")]
+pub fn find_roots<F>(f: &F, a: f64, b: f64) -> Vec<f64>
+where
+  F: Fn(f64) -> f64,
+{
+  let n = FIND_ROOTS_INTERVALS;
+  let min_separation = 1e-6 * (b - a) / n as f64;
+
+  let mut roots: Vec<f64> = Vec::new();
+  for (lo, hi) in find_all_brackets(f, a, b, n) {
+    let root = itp(f, lo, hi, f64::EPSILON);
+    if roots
+      .last()
+      .is_none_or(|&last| root - last > min_separation)
+    {
+      roots.push(root);
+    }
+  }
+
+  roots
+}
+
+/// Steps from `x0` by the signed `step` up to `limit`, refining the first root found.
+fn next_root<F>(f: &F, x0: f64, step: f64, limit: f64, tol: f64) -> Option<f64>
+where
+  F: Fn(f64) -> f64,
+{
+  let (mut x, mut fx) = (x0, f(x0));
+  if fx == 0.0 {
+    return Some(x);
+  }
+
+  // Whether x has not yet reached the limit in the direction of the step
+  let before = |x: f64| if step > 0.0 { x < limit } else { x > limit };
+
+  let mut i = 1.0;
+  while before(x) {
+    // The final step is cut short at the limit, and each point is computed from x0 so errors do not accumulate
+    let next = x0 + i * step;
+    let next = if before(next) { next } else { limit };
+    let f_next = f(next);
+
+    if f_next == 0.0 {
+      return Some(next);
+    } else if (f_next > 0.0) != (fx > 0.0) {
+      let (a, b) = if step > 0.0 {
+        ((x, fx), (next, f_next))
+      } else {
+        ((next, f_next), (x, fx))
+      };
+      return Some(itp(f, a, b, tol));
+    }
+
+    (x, fx) = (next, f_next);
+    i += 1.0;
   }
 
   None
@@ -189,6 +1442,9 @@ where
 /// Locate a negative value on the given function by first evaluating at `x`
 /// and then searching in the direction of `step` with successively doubling step sizes.
 /// Assumes f(x) is positive and it decreases in the direction of step.
+///
+/// As in [`find_bracket`], returns `None` once the search passes `min_x` or `max_x`, overflows, or exhausts its
+/// doublings.
 pub fn find_negative_from<F>(
   f: &F,
   x: impl Into<MaybeEval>,
@@ -204,24 +1460,23 @@ where
   let (mut a, mut fa) = x.into().evaled(f);
 
   if fa.is_sign_negative() {
-    return Some((a, fa).into());
+    return Some(MaybeEval::known(a, fa));
   }
 
   let mut b = a;
   let mut fb;
 
-  // TODO: Not forever
-  loop {
+  for _ in 0..MAX_DOUBLINGS {
     b += step;
     fb = f(b);
 
-    // We've explored up to the boundary without finding a bracket
-    if b < min_x || b > max_x {
+    // We've explored up to the boundary, or past the largest float, without finding a bracket
+    if b < min_x || b > max_x || !b.is_finite() {
       return None;
     }
 
     if fb < 0.0 {
-      return Some((b, fb).into());
+      return Some(MaybeEval::known(b, fb));
     }
 
     // We found a bracket; find a negative within it
@@ -234,9 +1489,12 @@ where
     fa = fb;
     step *= 2.0;
   }
+
+  None
 }
 
 /// Locates a negative value within the range bracket defined by `a` and `b`.
+/// Panics if `a >= b`.
 // TODO: At the moment, this function uses golden selection search. It would be nice to optionally use brent's algorithm from min
 pub fn locate_negative<F>(
   f: F,
@@ -244,6 +1502,23 @@ pub fn locate_negative<F>(
   b: impl Into<MaybeEval>,
   tol: f64,
 ) -> Option<MaybeEval>
+where
+  F: Fn(f64) -> f64,
+{
+  locate_negative_bounded(f, a, b, tol, usize::MAX)
+}
+
+/// Locates a negative value within the range bracket defined by `a` and `b`, as in [`locate_negative`], taking at
+/// most `max_iterations` iterations of the golden section search, each evaluating `f` at most twice.
+/// Returns `None` if no negative value is found before the bracket is within tolerance or the iterations run out.
+/// Panics if `a >= b`.
+pub fn locate_negative_bounded<F>(
+  f: F,
+  a: impl Into<MaybeEval>,
+  b: impl Into<MaybeEval>,
+  tol: f64,
+  max_iterations: usize,
+) -> Option<MaybeEval>
 where
   F: Fn(f64) -> f64,
 {
@@ -253,31 +1528,29 @@ where
 
   let (mut a, fa) = a.evaled(&f);
   if fa < 0.0 {
-    return Some((a, fa).into());
+    return Some(MaybeEval::known(a, fa));
   }
 
   let (mut b, fb) = b.evaled(&f);
   if fb < 0.0 {
-    return Some((b, fb).into());
+    return Some(MaybeEval::known(b, fb));
   }
 
   let epsilon = compute_epsilon(a, b, tol);
 
-  let phi: f64 = 0.5 * (1.0 + 5f64.sqrt());
-  let phi_inv: f64 = phi.recip();
-
-  let mut c = b - (b - a) * phi_inv;
-  let mut d = a + (b - a) * phi_inv;
+  let mut c = b - (b - a) * GOLDEN_RATIO_CONJUGATE;
+  let mut d = a + (b - a) * GOLDEN_RATIO_CONJUGATE;
 
-  while b - a > epsilon {
+  let mut iterations = 0;
+  while b - a > epsilon && iterations < max_iterations {
     let fc = f(c);
     if fc < 0.0 {
-      return Some((c, fc).into());
+      return Some(MaybeEval::known(c, fc));
     }
 
     let fd = f(d);
     if fd < 0.0 {
-      return Some((d, fd).into());
+      return Some(MaybeEval::known(d, fd));
     }
 
     if fc < fd {
@@ -286,8 +1559,9 @@ where
       a = c;
     }
 
-    c = b - (b - a) * phi_inv;
-    d = a + (b - a) * phi_inv;
+    c = b - (b - a) * GOLDEN_RATIO_CONJUGATE;
+    d = a + (b - a) * GOLDEN_RATIO_CONJUGATE;
+    iterations += 1;
   }
 
   None
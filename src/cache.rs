@@ -0,0 +1,156 @@
+//! Sharing function evaluations between several methods applied to the same function.
+//!
+//! Running several methods on the same function, for example to compare them, evaluates it again for each method,
+//! even at points many methods probe in common, such as the endpoints and midpoint of a bracket. An [`EvalCache`]
+//! remembers every evaluation, so each distinct point is computed only once:
+//!
+//! ```
+//! use uniarity::bracket::{bisection, itp};
+//! use uniarity::cache::EvalCache;
+//!
+//! let f = |x: f64| x * x - 2.0;
+//! let cache = EvalCache::new();
+//! let g = cache.bind(&f);
+//!
+//! let x1 = bisection(&g, 0.0, 2.0, 1e-15);
+//! let x2 = itp(&g, 0.0, 2.0, 1e-15);
+//! assert!((x1 - x2).abs() < 1e-14);
+//! ```
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+
+use ordered_float::OrderedFloat;
+
+/// A cache of function evaluations, keyed on the exact x-coordinate.
+///
+/// The cache stores values for a single function; it is the caller's responsibility not to share one cache between
+/// different functions. It uses interior mutability without locking, so it is not `Sync`. Threads solving in
+/// parallel should each use their own cache.
+#[derive(Debug, Default)]
+pub struct EvalCache {
+  values: RefCell<HashMap<OrderedFloat<f64>, f64>>,
+}
+
+impl EvalCache {
+  /// Constructs an empty cache.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Returns `f(x)`, evaluating it only if it has not already been cached.
+  pub fn eval<F>(&self, f: &F, x: f64) -> f64
+  where
+    F: Fn(f64) -> f64,
+  {
+    if let Some(&fx) = self.values.borrow().get(&OrderedFloat(x)) {
+      return fx;
+    }
+
+    // `f` is evaluated without holding the borrow, so it may itself consult the cache
+    let fx = f(x);
+    self.values.borrow_mut().insert(OrderedFloat(x), fx);
+    fx
+  }
+
+  /// Binds the cache to `f`, returning a function which may be passed to any method in place of `f`.
+  pub fn bind<'a, F>(&'a self, f: &'a F) -> impl Fn(f64) -> f64 + 'a
+  where
+    F: Fn(f64) -> f64,
+  {
+    move |x| self.eval(f, x)
+  }
+
+  /// The number of distinct points which have been evaluated.
+  pub fn len(&self) -> usize {
+    self.values.borrow().len()
+  }
+
+  /// Whether no points have been evaluated.
+  pub fn is_empty(&self) -> bool {
+    self.values.borrow().is_empty()
+  }
+
+  /// Forgets all cached evaluations.
+  pub fn clear(&self) {
+    self.values.borrow_mut().clear();
+  }
+}
+
+/// A function which remembers its evaluations, keyed on the exact x-coordinate, and counts how often they are reused,
+/// such as to tune a method which evaluates an expensive function at the same point more than once.
+///
+/// As for [`crate::Counted`], the memoized function is obtained with [`Memoized::as_fn`], which may be passed to any
+/// method in place of the original:
+///
+/// ```
+/// use uniarity::bracket::bisection;
+/// use uniarity::Memoized;
+///
+/// let f = Memoized::new(|x: f64| x * x - 2.0);
+/// let x1 = bisection(&f.as_fn(), 0.0, 2.0, 1e-15);
+/// let x2 = bisection(&f.as_fn(), 0.0, 2.0, 1e-15);
+/// assert_eq!(x1, x2);
+/// assert_eq!(f.hits(), f.misses());
+/// ```
+///
+/// Unlike an [`EvalCache`], which is bound to a function separately, a `Memoized` owns its function, so the cache
+/// cannot be shared with a different function by mistake.
+#[derive(Debug)]
+pub struct Memoized<F> {
+  f: F,
+  cache: EvalCache,
+  hits: Cell<usize>,
+  misses: Cell<usize>,
+}
+
+impl<F> Memoized<F>
+where
+  F: Fn(f64) -> f64,
+{
+  /// Wraps `f`, with nothing yet remembered.
+  pub fn new(f: F) -> Self {
+    Self {
+      f,
+      cache: EvalCache::new(),
+      hits: Cell::new(0),
+      misses: Cell::new(0),
+    }
+  }
+
+  /// Returns `f(x)`, evaluating it only if it has not already been remembered.
+  pub fn eval(&self, x: f64) -> f64 {
+    let len = self.cache.len();
+    let fx = self.cache.eval(&self.f, x);
+
+    let counter = if self.cache.len() > len {
+      &self.misses
+    } else {
+      &self.hits
+    };
+    counter.set(counter.get() + 1);
+    fx
+  }
+
+  /// Returns a function which may be passed to any method in place of `f`, remembering each evaluation.
+  pub fn as_fn(&self) -> impl Fn(f64) -> f64 + '_ {
+    move |x| self.eval(x)
+  }
+
+  /// The number of evaluations answered from memory.
+  pub fn hits(&self) -> usize {
+    self.hits.get()
+  }
+
+  /// The number of evaluations which called `f`, which is also the number of distinct points evaluated.
+  pub fn misses(&self) -> usize {
+    self.misses.get()
+  }
+
+  /// Forgets all remembered evaluations, and resets both counters to zero.
+  pub fn clear(&self) {
+    self.cache.clear();
+    self.hits.set(0);
+    self.misses.set(0);
+  }
+}
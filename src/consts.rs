@@ -0,0 +1,12 @@
+//! Mathematical constants shared by the methods in this crate, each correctly rounded to `f64`.
+
+/// The golden ratio, `phi = (1 + sqrt(5)) / 2`.
+pub const GOLDEN_RATIO: f64 = 1.618033988749895;
+
+/// The conjugate of the golden ratio, `1 / phi = phi - 1`. A golden section search keeps this fraction of the bracket
+/// at each step.
+pub const GOLDEN_RATIO_CONJUGATE: f64 = 0.6180339887498949;
+
+/// The golden section, `(phi - 1)^2 = 2 - phi`. A golden section step moves this fraction of the way into the larger
+/// part of the bracket.
+pub const GOLDEN_SECTION: f64 = 0.38196601125010515;
@@ -0,0 +1,23 @@
+//! Finite-difference approximations of derivatives, for use with methods such as [`crate::initial::newtons_method`]
+//! when no analytic or automatic derivative is available.
+//!
+//! Both approximations use central differences, whose truncation error falls off with `h^2`. The rounding error
+//! grows as `h` shrinks, so the step should not simply be made as small as possible. For a function evaluated to
+//! near machine precision, the total error is smallest around `h = eps^(1/3) * max(|x|, 1)` (about `6e-6` at unit
+//! scale) for the first derivative, and `h = eps^(1/4) * max(|x|, 1)` (about `1e-4`) for the second.
+
+/// Approximates `f'(x)` by the central difference `(f(x + h) - f(x - h)) / 2h`.
+pub fn numerical_derivative<F>(f: &F, x: f64, h: f64) -> f64
+where
+  F: Fn(f64) -> f64,
+{
+  (f(x + h) - f(x - h)) / (2.0 * h)
+}
+
+/// Approximates `f''(x)` by the central difference `(f(x + h) - 2f(x) + f(x - h)) / h^2`.
+pub fn numerical_second_derivative<F>(f: &F, x: f64, h: f64) -> f64
+where
+  F: Fn(f64) -> f64,
+{
+  (f(x + h) - 2.0 * f(x) + f(x - h)) / (h * h)
+}
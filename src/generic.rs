@@ -0,0 +1,84 @@
+//! Versions of the core methods of this crate which are generic over the floating point type, such as `f32`.
+//!
+//! The methods elsewhere in this crate work in `f64`, so that closures passed to them need no type annotations.
+//! The methods of this module share their implementations, and accept any type implementing [`Float`], as in
+//! `bisection::<f32, _>(...)`. Constants such as the golden section are converted to the type through the trait, and
+//! tolerances are clamped to its machine precision rather than that of `f64`.
+
+pub use num_traits::Float;
+
+use crate::bracket::{bisect, ItpItem, ItpIter};
+use crate::initial::{newtons_method_impl, secant_impl};
+#[cfg(feature = "std")]
+use crate::min::converged_brent;
+use crate::MaybeEval;
+
+/// Converts an `f64` constant to the floating point type `T`, rounding it if necessary.
+pub(crate) fn constant<T: Float>(x: f64) -> T {
+  T::from(x).expect("a float type can represent any f64, if only approximately")
+}
+
+/// Locates the root within a bracket using the bisection method, as in [`crate::bracket::bisection`].
+/// Requires that `f` is continuous and that `f(a)` and `f(b)` have opposite signs.
+/// Panics if `a > b`.
+pub fn bisection<T, F>(f: &F, a: T, b: T, tol: T) -> T
+where
+  T: Float,
+  F: Fn(T) -> T,
+{
+  if a == b {
+    return a;
+  }
+  assert!(a < b);
+
+  bisect(f, a, f(a), b, tol)
+}
+
+/// Locates the root within a bracket using the ITP method, as in [`crate::bracket::itp`].
+/// Requires that `f` is continuous and that `f(a)` and `f(b)` have opposite signs.
+/// Panics if `a > b`.
+pub fn itp<T, F>(f: &F, a: T, b: T, tol: T) -> T
+where
+  T: Float,
+  F: Fn(T) -> T,
+{
+  assert!(a <= b);
+
+  match ItpIter::from_bracket(f, MaybeEval::unknown(a), MaybeEval::unknown(b), tol).last() {
+    Some(ItpItem::Root(x)) => x,
+    _ => unreachable!("ItpIter always terminates with a root"),
+  }
+}
+
+/// Uses Newton's method to locate the root of a function, given an initial value, as in
+/// [`crate::initial::newtons_method`].
+pub fn newtons_method<T, F, Fp>(f: &F, g: &Fp, x0: T, tol: T) -> T
+where
+  T: Float,
+  F: Fn(T) -> T,
+  Fp: Fn(T) -> T,
+{
+  newtons_method_impl(f, g, x0, tol, 100).root
+}
+
+/// Uses the secant method to locate the root of a function, given an initial pair of values, as in
+/// [`crate::initial::secant`].
+pub fn secant<T, F>(f: &F, x0: T, x1: T, tol: T) -> T
+where
+  T: Float,
+  F: Fn(T) -> T,
+{
+  secant_impl(f, x0, x1, tol, 100).root
+}
+
+/// Returns the minimum of a function within the given bracket as `(x, f(x))`, using Brent's algorithm as in
+/// [`crate::min::min`]. Requires the `std` feature.
+#[cfg(feature = "std")]
+pub fn min<T, F>(f: &F, a: T, b: T, tol: T) -> (T, T)
+where
+  T: Float,
+  F: Fn(T) -> T,
+{
+  let brent = converged_brent(f, a, b, a + constant::<T>(0.5) * (b - a), tol);
+  (brent.x, brent.fx)
+}
@@ -1,33 +1,524 @@
 //! Methods to determine the a root of a univariate function using an initial approximation.
 
+use num_complex::Complex;
+
+use crate::compute_epsilon;
+use crate::diff::numerical_derivative;
+use crate::generic::{constant, Float};
+
 /// Uses the secant method to locate the root of a function, given an initial pair of values.
-/// Terminates after |x0 - x1| <= tol, |f(x0) - f(x1)| <= tol, or after 100 iterations.
-pub fn secant<F>(f: &F, mut x0: f64, mut x1: f64, tol: f64) -> f64
+/// Terminates after |x0 - x1| <= 2 tol max(|x0|, |x1|), |f(x0) - f(x1)| <= tol, or after 100 iterations.
+///
+/// The step test is relative to the iterates, as in the bracketing methods, so a `tol` of [`f64::EPSILON`] asks for
+/// a root accurate to machine precision at any scale.
+pub fn secant<F>(f: &F, x0: f64, x1: f64, tol: f64) -> f64
+where
+  F: Fn(f64) -> f64,
+{
+  secant_with(f, x0, x1, tol, 100)
+}
+
+/// Uses the secant method to locate the root of a function, as in [`secant`], but terminates after
+/// `max_iterations` iterations rather than 100, returning the latest iterate.
+pub fn secant_with<F>(f: &F, x0: f64, x1: f64, tol: f64, max_iterations: usize) -> f64
+where
+  F: Fn(f64) -> f64,
+{
+  secant_impl(f, x0, x1, tol, max_iterations).root
+}
+
+/// Uses the secant method to locate the root of a function, as in [`secant`], reporting whether it converged.
+///
+/// The method is considered to have converged if, before the iteration cap, the last step passed the relative step test
+/// of [`secant`], or the residual at the root is at most `tol`. Terminating because `|f(x0) - f(x1)| <= tol` is
+/// otherwise not convergence, as happens where the iterates run off along a flat region of `f`.
+pub fn secant_report<F>(f: &F, x0: f64, x1: f64, tol: f64) -> Convergence
+where
+  F: Fn(f64) -> f64,
+{
+  secant_impl(f, x0, x1, tol, 100)
+}
+
+/// Uses the secant method to locate the root of a function, given an initial pair of values, with a scale-invariant
+/// tolerance, as in [`newtons_method_rel`]. Terminates after |f(x)| <= tol * min(|f(x0)|, |f(x1)|),
+/// |dx| <= tol * |x|, f(x0) = f(x1), or after 100 iterations.
+///
+/// Unlike [`secant`], neither criterion compares a function value to `tol` directly, so the result is unchanged by
+/// rescaling `f` or `x`, for roots anywhere from `1e-12` to `1e9`.
+pub fn secant_rel<F>(f: &F, mut x0: f64, mut x1: f64, tol: f64) -> f64
 where
   F: Fn(f64) -> f64,
 {
   let mut f0 = f(x0);
   let mut f1 = f(x1);
+  let residual_tol = tol * f0.abs().min(f1.abs());
 
   let max_iterations = 100;
   let mut iterations = 0;
 
-  while (x1 - x0).abs() > tol && (f1 - f0).abs() > tol && iterations < max_iterations {
+  while f1.abs() > residual_tol && iterations < max_iterations {
+    if f1 == f0 {
+      break;
+    }
+
+    let dx = f1 * (x1 - x0) / (f1 - f0);
+    (x0, f0) = (x1, f1);
+    x1 -= dx;
+    f1 = f(x1);
+    iterations += 1;
+
+    if dx.abs() <= tol * x1.abs() {
+      break;
+    }
+  }
+
+  x1
+}
+
+pub(crate) fn secant_impl<T, F>(
+  f: &F,
+  mut x0: T,
+  mut x1: T,
+  tol: T,
+  max_iterations: usize,
+) -> Convergence<T>
+where
+  T: Float,
+  F: Fn(T) -> T,
+{
+  let mut f0 = f(x0);
+  let mut f1 = f(x1);
+
+  let mut iterations = 0;
+
+  while !is_small_step(x0, x1, tol) && (f1 - f0).abs() > tol && iterations < max_iterations {
     let x = x1 - f1 * (x1 - x0) / (f1 - f0);
     (x0, f0) = (x1, f1);
     (x1, f1) = (x, f(x));
     iterations += 1;
   }
 
-  x1
+  Convergence {
+    root: x1,
+    iterations,
+    converged: is_small_step(x0, x1, tol) || f1.abs() <= tol,
+    final_residual: f1,
+  }
+}
+
+/// Uses Steffensen's method to locate the root of a function, given a single initial value.
+/// Terminates after |f(x)| <= tol, a step of |dx| <= 2 tol max(|x|, |x + dx|), once the slope estimate vanishes or
+/// is not finite, or after `max_iterations` iterations.
+///
+/// Each iteration takes the step `dx = -f(x)^2 / (f(x + f(x)) - f(x))`, which is Newton's step with the derivative
+/// replaced by the slope across `[x, x + f(x)]`. As the residual shrinks, so does this interval, so the method
+/// converges quadratically near a simple root without a derivative, at two evaluations per iteration. The interval
+/// is only meaningful if `|f(x)|` is comparable to the distance to the root: far from the root, or for a steep `f`,
+/// the steps may wander or diverge. It is best started near the root, such as from a rough bracket.
+///
+/// Once `|f(x)|` falls below `sqrt(eps) max(|x|, 1)`, the interval is so narrow that rounding would swamp the slope,
+/// so the slope is taken across an interval of that width instead.
+pub fn steffensen<F>(f: &F, mut x: f64, tol: f64, max_iterations: usize) -> f64
+where
+  F: Fn(f64) -> f64,
+{
+  let mut fx = f(x);
+
+  for _ in 0..max_iterations {
+    if fx.abs() <= tol {
+      break;
+    }
+
+    // The step is formed from the slope, rather than from f(x)^2, so that squaring a large residual cannot overflow
+    let min_h = f64::EPSILON.sqrt() * x.abs().max(1.0);
+    let h = if fx.abs() < min_h {
+      min_h.copysign(fx)
+    } else {
+      fx
+    };
+    let slope = (f(x + h) - fx) / h;
+    if slope == 0.0 || !slope.is_finite() {
+      break;
+    }
+
+    let next = x - fx / slope;
+    let step_converged = is_small_step(x, next, tol);
+
+    x = next;
+    fx = f(x);
+
+    if step_converged {
+      break;
+    }
+  }
+
+  x
+}
+
+/// Locates a fixed point `x = g(x)` of the map `g`, given an initial value, by accelerating the iteration
+/// `x <- g(x)` with Aitken's delta-squared process.
+/// Terminates after a step of |dx| <= 2 tol max(|x|, |x + dx|), or after `max_iterations` iterations.
+///
+/// Each iteration applies `g` twice from `x0`, giving `x1` and `x2`, and extrapolates the three iterates to the limit
+/// `x0 - (x1 - x0)^2 / (x2 - 2 x1 + x0)` of a linearly converging sequence. Where plain iteration converges linearly,
+/// this converges quadratically, at two evaluations per iteration. Where the second difference in the denominator is
+/// lost to rounding, the extrapolation is meaningless, and the plain iterate `x2` is taken instead.
+pub fn aitken<F>(g: &F, mut x: f64, tol: f64, max_iterations: usize) -> f64
+where
+  F: Fn(f64) -> f64,
+{
+  for _ in 0..max_iterations {
+    let x1 = g(x);
+    let x2 = g(x1);
+
+    let dx = x1 - x;
+    let d2x = x2 - 2.0 * x1 + x;
+    let noise = f64::EPSILON * (x.abs() + 2.0 * x1.abs() + x2.abs());
+
+    // The step is formed from the ratio, rather than from dx^2, so that squaring a large difference cannot overflow
+    let next = if d2x.abs() > noise {
+      x - dx * (dx / d2x)
+    } else {
+      x2
+    };
+
+    let step_converged = is_small_step(x, next, tol);
+    x = next;
+
+    if step_converged {
+      break;
+    }
+  }
+
+  x
+}
+
+/// Uses Muller's method to locate a root of a function, which may be complex, given three initial values.
+/// Terminates after |f(x)| <= tol, a step of |dx| <= 2 tol max(|x|, |x + dx|), once the parabola through the last
+/// three iterates is degenerate, or after 100 iterations.
+///
+/// Each iteration fits a parabola through the last three iterates, and steps to whichever of its roots is closer to
+/// the last one. As the roots of a parabola may be complex even where the parabola, like `f`, is real along the real
+/// axis, the iterates may leave the real axis towards complex roots, which real methods such as [`secant`] cannot
+/// reach. Convergence near a simple root is superlinear, of order about 1.84, at one evaluation per iteration.
+pub fn mullers_method<F>(
+  f: &F,
+  x0: Complex<f64>,
+  x1: Complex<f64>,
+  x2: Complex<f64>,
+  tol: f64,
+) -> Complex<f64>
+where
+  F: Fn(Complex<f64>) -> Complex<f64>,
+{
+  let (mut x0, mut x1, mut x2) = (x0, x1, x2);
+  let (mut f0, mut f1, mut f2) = (f(x0), f(x1), f(x2));
+
+  for _ in 0..100 {
+    if f2.norm() <= tol {
+      break;
+    }
+
+    // The divided differences of the parabola through the three iterates, written about x2
+    let d21 = (f2 - f1) / (x2 - x1);
+    let d10 = (f1 - f0) / (x1 - x0);
+    let d210 = (d21 - d10) / (x2 - x0);
+    let w = d21 + (x2 - x1) * d210;
+
+    // The root of the parabola closest to x2 has the larger denominator
+    let disc = (w * w - 4.0 * f2 * d210).sqrt();
+    let denominator = if (w + disc).norm() >= (w - disc).norm() {
+      w + disc
+    } else {
+      w - disc
+    };
+    if denominator.norm() == 0.0 || !denominator.is_finite() {
+      break;
+    }
+
+    let x3 = x2 - 2.0 * f2 / denominator;
+    let step_converged = (x3 - x2).norm() <= compute_epsilon(x2.norm(), x3.norm(), tol);
+
+    (x0, x1, x2) = (x1, x2, x3);
+    (f0, f1, f2) = (f1, f2, f(x3));
+
+    if step_converged {
+      break;
+    }
+  }
+
+  x2
+}
+
+/// Whether a step from `x0` to `x1` is small relative to the iterates, as judged by [`compute_epsilon`] in the
+/// bracketing methods. A step to or from a non-finite iterate is never small.
+fn is_small_step<T: Float>(x0: T, x1: T, tol: T) -> bool {
+  x0.is_finite() && x1.is_finite() && (x1 - x0).abs() <= compute_epsilon(x0, x1, tol)
+}
+
+/// The outcome of an iterative method, as returned by [`newtons_method_report`] and [`secant_report`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Convergence<T = f64> {
+  /// The final iterate.
+  pub root: T,
+  /// The number of iterations taken.
+  pub iterations: usize,
+  /// Whether the method's tolerance test passed, rather than the method stalling or reaching its iteration cap.
+  pub converged: bool,
+  /// The value of the function at the final iterate.
+  pub final_residual: T,
 }
 
 /// Uses Newton's method to locate the root of a function, given an initial value.
+/// Terminates after |f(x)| <= tol, a step of |dx| <= 2 tol max(|x|, |x + dx|), or after 100 iterations.
+///
+/// The step test is relative to the iterates, as in the bracketing methods, so a `tol` of [`f64::EPSILON`] asks for
+/// a root accurate to machine precision at any scale, even where rounding keeps `|f(x)|` above `tol`.
+///
+/// Where `|g(x)| <= tol`, the Newton step is unbounded, so `x` is instead moved by `sqrt(eps) max(|x|, 1)` towards
+/// whichever side has the smaller `|f|`, doubling the move for as long as `f` stays flat. The Newton steps which
+/// follow are bounded by a radius which starts at the last move and doubles with each iteration, so that they cannot
+/// be thrown far by the nearly flat derivative just past the flat point.
+pub fn newtons_method<F, Fp>(f: &F, g: &Fp, x0: f64, tol: f64) -> f64
+where
+  F: Fn(f64) -> f64,
+  Fp: Fn(f64) -> f64,
+{
+  newtons_method_with(f, g, x0, tol, 100)
+}
+
+/// Uses Newton's method to locate the root of a function, as in [`newtons_method`], but terminates after
+/// `max_iterations` iterations rather than 100, returning the latest iterate.
+pub fn newtons_method_with<F, Fp>(f: &F, g: &Fp, x0: f64, tol: f64, max_iterations: usize) -> f64
+where
+  F: Fn(f64) -> f64,
+  Fp: Fn(f64) -> f64,
+{
+  newtons_method_impl(f, g, x0, tol, max_iterations).root
+}
+
+/// Uses Newton's method to locate the root of a function, as in [`newtons_method`], reporting whether it converged.
+///
+/// The method is considered to have converged if `|f(x)| <= tol`, or the last step was a full Newton step which
+/// passed the relative step test of [`newtons_method`], before the iteration cap. The moves away from flat points,
+/// and steps cut short by the bounding radius, are never considered converged, however small they are.
+pub fn newtons_method_report<F, Fp>(f: &F, g: &Fp, x0: f64, tol: f64) -> Convergence
+where
+  F: Fn(f64) -> f64,
+  Fp: Fn(f64) -> f64,
+{
+  newtons_method_impl(f, g, x0, tol, 100)
+}
+
+pub(crate) fn newtons_method_impl<T, F, Fp>(
+  f: &F,
+  g: &Fp,
+  mut x: T,
+  tol: T,
+  max_iterations: usize,
+) -> Convergence<T>
+where
+  T: Float,
+  F: Fn(T) -> T,
+  Fp: Fn(T) -> T,
+{
+  let mut fx = f(x);
+  let mut gx = g(x);
+
+  let mut iterations = 0;
+  let mut step_converged = false;
+  let mut radius = T::infinity();
+
+  while fx.abs() > tol && iterations < max_iterations {
+    let two = constant::<T>(2.0);
+    // Only a full Newton step is small because x is near a root
+    let mut newton_step = false;
+    let next = if gx.abs() > tol {
+      // Steps are bounded by a radius which doubles every iteration after leaving a flat point, so that the large
+      // steps taken just beyond it cannot overshoot far
+      let mut dx = fx / gx;
+      if dx.abs() > radius {
+        dx = radius.copysign(dx);
+      } else {
+        newton_step = true;
+      }
+      radius = two * radius;
+      x - dx
+    } else {
+      // At a flat point the Newton step is unbounded, so perturb x instead, towards whichever side has the smaller
+      // residual. The perturbation doubles for as long as f stays flat
+      radius = if radius.is_infinite() {
+        T::epsilon().sqrt() * x.abs().max(T::one())
+      } else {
+        two * radius
+      };
+      let (left, right) = (x - radius, x + radius);
+      if f(left).abs() < f(right).abs() {
+        left
+      } else {
+        right
+      }
+    };
+    step_converged = newton_step && is_small_step(x, next, tol);
+
+    x = next;
+    fx = f(x);
+    gx = g(x);
+    iterations += 1;
+
+    if step_converged {
+      break;
+    }
+  }
+
+  Convergence {
+    root: x,
+    iterations,
+    converged: fx.abs() <= tol || step_converged,
+    final_residual: fx,
+  }
+}
+
+/// Uses Newton's method to locate the root of a function, given an initial value, with a scale-invariant tolerance.
+/// Terminates after |f(x)| <= tol * |f(x0)|, |dx| <= tol * |x|, g(x) = 0, or after 100 iterations.
+///
+/// The residual criterion is relative to the initial residual, so it is unchanged by rescaling `f`, and the step
+/// criterion is relative to the iterate, so it is unchanged by rescaling `x`. Whichever is met first ends the
+/// iteration. Near a simple root the step criterion is usually met first, since a step of `dx` leaves a residual of
+/// order `dx^2`; the residual criterion ends the iteration near a root at zero, where no step is small relative to
+/// `x`, and on functions which are flat near the root, where steps stay large while the residual vanishes.
+pub fn newtons_method_rel<F, Fp>(f: &F, g: &Fp, mut x: f64, tol: f64) -> f64
+where
+  F: Fn(f64) -> f64,
+  Fp: Fn(f64) -> f64,
+{
+  let mut fx = f(x);
+  let residual_tol = tol * fx.abs();
+
+  let max_iterations = 100;
+  let mut iterations = 0;
+
+  while fx.abs() > residual_tol && iterations < max_iterations {
+    let gx = g(x);
+    if gx == 0.0 {
+      break;
+    }
+
+    let dx = fx / gx;
+    x -= dx;
+    fx = f(x);
+    iterations += 1;
+
+    if dx.abs() <= tol * x.abs() {
+      break;
+    }
+  }
+
+  x
+}
+
+/// Uses Newton's method to solve a system of equations which has been reduced to a single scalar equation, given an
+/// initial value for the remaining unknown.
+/// Terminates after |r(x)| <= tol, |r'(x)| <= tol, or after 100 iterations.
+///
+/// Many problems in several unknowns reduce to one scalar equation `r(x) = 0` once the other unknowns are eliminated,
+/// by solving the remaining equations for them as functions of `x`. Given `x`, `residual_and_derivative` performs
+/// this inner solve and returns the residual `r(x)` along with its total derivative `dr/dx`, including the
+/// dependence of the eliminated unknowns on `x`, which follows from the implicit function theorem. Computing both
+/// together shares the inner solve between them.
+///
+/// ```
+/// use uniarity::initial::solve_scalarized;
+///
+/// // Solve x^2 + y^2 = 1 subject to y^3 + y = x, eliminating y
+/// let inner = |x: f64| {
+///   let mut y = x;
+///   for _ in 0..50 {
+///     y -= (y * y * y + y - x) / (3.0 * y * y + 1.0);
+///   }
+///   y
+/// };
+///
+/// let x = solve_scalarized(
+///   &|x: f64| {
+///     let y = inner(x);
+///     // dy/dx, by differentiating y^3 + y = x implicitly
+///     let dy = 1.0 / (3.0 * y * y + 1.0);
+///     (x * x + y * y - 1.0, 2.0 * x + 2.0 * y * dy)
+///   },
+///   0.5,
+///   1e-15,
+/// );
+///
+/// let y = inner(x);
+/// assert!((x * x + y * y - 1.0).abs() <= 1e-15);
+/// assert!((y * y * y + y - x).abs() <= 1e-15);
+/// ```
+pub fn solve_scalarized<F>(residual_and_derivative: &F, mut x: f64, tol: f64) -> f64
+where
+  F: Fn(f64) -> (f64, f64),
+{
+  let (mut r, mut dr) = residual_and_derivative(x);
+
+  let max_iterations = 100;
+  let mut iterations = 0;
+
+  while r.abs() > tol && dr.abs() > tol && iterations < max_iterations {
+    x -= r / dr;
+    (r, dr) = residual_and_derivative(x);
+    iterations += 1;
+  }
+
+  x
+}
+
+/// Uses Newton's method to locate the root of a function within the bounds `[lo, hi]`, given an initial value.
+/// Each iterate which would leave the bounds is projected back onto the nearest bound.
+/// Terminates after |f(x)| <= tol, |g(x)| <= tol, when an iterate is stuck on a bound, or after 100 iterations.
+///
+/// Unlike the bracketing methods in [`crate::bracket`], this does not require `f(lo)` and `f(hi)` to have opposite
+/// signs, and so cannot guarantee convergence: it only prevents the iterates from leaving the region of interest.
+/// If no root lies within the bounds, the result is typically the bound towards which `f` decreases in magnitude.
+/// Panics if `lo > hi`.
+pub fn newtons_method_bounded<F, Fp>(f: &F, g: &Fp, lo: f64, hi: f64, x0: f64, tol: f64) -> f64
+where
+  F: Fn(f64) -> f64,
+  Fp: Fn(f64) -> f64,
+{
+  assert!(lo <= hi);
+
+  let mut x = x0.clamp(lo, hi);
+  let mut fx = f(x);
+  let mut gx = g(x);
+
+  let max_iterations = 100;
+  let mut iterations = 0;
+
+  while fx.abs() > tol && gx.abs() > tol && iterations < max_iterations {
+    let next = (x - fx / gx).clamp(lo, hi);
+    if next == x {
+      break;
+    }
+
+    x = next;
+    fx = f(x);
+    gx = g(x);
+    iterations += 1;
+  }
+
+  x
+}
+
+/// Uses Halley's method to locate the root of a function, given an initial value.
 /// Terminates after |f(x)| <= tol, |g(x)| <= tol, or after 100 iterations.
-pub fn newtons_method<F, Fp>(f: &F, g: &Fp, mut x: f64, tol: f64) -> f64
+///
+/// Near a simple root this converges cubically, using the second derivative `h`. Where the denominator of the
+/// Halley step, `2 g(x)^2 - f(x) h(x)`, is zero, a Newton step is taken instead.
+pub fn halleys_method<F, Fp, Fpp>(f: &F, g: &Fp, h: &Fpp, mut x: f64, tol: f64) -> f64
 where
   F: Fn(f64) -> f64,
   Fp: Fn(f64) -> f64,
+  Fpp: Fn(f64) -> f64,
 {
   let mut fx = f(x);
   let mut gx = g(x);
@@ -36,7 +527,12 @@ where
   let mut iterations = 0;
 
   while fx.abs() > tol && gx.abs() > tol && iterations < max_iterations {
-    x -= fx / gx;
+    let denominator = 2.0 * gx * gx - fx * h(x);
+    x -= if denominator != 0.0 {
+      2.0 * fx * gx / denominator
+    } else {
+      fx / gx
+    };
     fx = f(x);
     gx = g(x);
     iterations += 1;
@@ -46,7 +542,17 @@ where
 }
 
 /// Uses Laguerre's method to locate the root of a function, given an initial value.
-/// Terminates after |f(x)| <= tol, |g(x)| <= tol, or after 100 iterations.
+/// Terminates after |f(x)| <= tol, a step of |dx| <= tol, a step which is not finite, or after 100 iterations.
+///
+/// `n` is the degree of the polynomial the method assumes `f` to be. Each iteration takes the step
+/// `dx = n / (G ± sqrt((n - 1) (n H - G^2)))`, where `G = f'/f` and `H = G^2 - f''/f`, which is exact for a
+/// polynomial of degree `n` whose other roots all coincide. The sign is chosen to match `G`, maximizing the magnitude
+/// of the denominator and so taking the smaller step. For a polynomial, `n` should be its degree, from which the
+/// method converges cubically to a simple root from almost any starting point; for other functions, `n = 1` reduces
+/// it to Newton's method, and larger `n` take more cautious steps.
+///
+/// Where the discriminant is negative, the step would be complex, heading for a complex root. The discriminant is then
+/// clamped to zero, so the iterate stays on the real line, taking the step `n / G`.
 pub fn laguerres_method<F, Fp, Fpp>(f: &F, g: &Fp, h: &Fpp, n: f64, mut x: f64, tol: f64) -> f64
 where
   F: Fn(f64) -> f64,
@@ -66,7 +572,8 @@ where
   while fx.abs() > tol && a.abs() > tol && iterations < max_iterations {
     let G = gx / fx;
     let H = G * G - hx / fx;
-    a = n / (G + ((n - 1.0) * (n * H - G * G)).sqrt().copysign(G));
+    let discriminant = ((n - 1.0) * (n * H - G * G)).max(0.0);
+    a = n / (G + discriminant.sqrt().copysign(G));
     if a.is_nan() || a.is_infinite() {
       break;
     }
@@ -79,3 +586,85 @@ where
 
   x
 }
+
+/// Returns the rate `dx/dp` at which a root `x` of `f(x, p) = 0` moves as the parameter `p` changes.
+///
+/// By the implicit function theorem, `dx/dp = -(df/dp) / (df/dx)`, with the partial derivatives evaluated at
+/// `(root, p)`. Each is approximated by [`numerical_derivative`], with the step `eps^(1/3) * max(|v|, 1)` for the
+/// variable `v` being differentiated, which balances truncation and rounding error for a function evaluated to near
+/// machine precision.
+///
+/// At a turning point of the branch of roots, where `df/dx = 0`, the root does not vary smoothly with `p`: it either
+/// vanishes or splits as `p` changes, and the sensitivity is unbounded. Returns `NaN` if `|df/dx| <= tol`, so that
+/// near-singular roots are not reported with a huge but meaningless sensitivity.
+pub fn root_sensitivity<F>(f: &F, root: f64, p: f64, tol: f64) -> f64
+where
+  F: Fn(f64, f64) -> f64,
+{
+  let step = |v: f64| f64::EPSILON.cbrt() * v.abs().max(1.0);
+
+  let df_dx = numerical_derivative(&|x| f(x, p), root, step(root));
+  if df_dx.abs() <= tol {
+    return f64::NAN;
+  }
+
+  let df_dp = numerical_derivative(&|p| f(root, p), p, step(p));
+  -df_dp / df_dx
+}
+
+/// Traces a root `x(t)` of `f(x, t) = 0` through the parameter values `ts`, returning one root per parameter value.
+///
+/// Each root is found with the secant method, starting from the root at the previous parameter value (or `x0` for
+/// the first), so the parameter values should be ordered and closely spaced along the path being followed.
+///
+/// A branch of roots may end at a fold, where it turns back on itself, or jump between branches. This is detected
+/// when `f` does not change sign across the root found by the secant method, or when the rate of change of the root,
+/// `dx/dt`, grows more than tenfold between consecutive steps. Rates below one are compared as if they were one, so
+/// that a root which is momentarily stationary does not make any later motion look like a jump. The root at the
+/// parameter value where this happens, and at every later parameter value, is reported as `NaN`. Tangent roots are
+/// therefore treated as folds.
+///
+/// A repeated parameter value yields the same root again, and does not count as a step.
+///
+/// Requires the `std` feature.
+#[cfg(feature = "std")]
+pub fn trace_root<F>(f: &F, x0: f64, ts: &[f64], tol: f64) -> Vec<f64>
+where
+  F: Fn(f64, f64) -> f64,
+{
+  let max_slope_ratio = 10.0;
+  let min_slope = 1.0;
+
+  let mut roots = Vec::with_capacity(ts.len());
+  let mut prev: Option<(f64, f64)> = None;
+  let mut prev_slope: Option<f64> = None;
+  let mut x = x0;
+
+  for &t in ts {
+    let g = |x: f64| f(x, t);
+    let h = f64::EPSILON.sqrt() * x.abs().max(1.0);
+    let root = secant(&g, x, x + h, tol);
+
+    // Past a fold, the secant method may stall on a nearby point which is not a root at all
+    let (lo, hi) = (g(root - h), g(root + h));
+    let crosses = (lo < 0.0 && hi > 0.0) || (lo > 0.0 && hi < 0.0);
+    if !crosses && g(root) != 0.0 {
+      break;
+    }
+
+    if let Some((prev_t, prev_x)) = prev.filter(|&(prev_t, _)| prev_t != t) {
+      let slope = ((root - prev_x) / (t - prev_t)).abs();
+      if prev_slope.is_some_and(|prev_slope| slope > max_slope_ratio * prev_slope.max(min_slope)) {
+        break;
+      }
+      prev_slope = Some(slope);
+    }
+
+    roots.push(root);
+    prev = Some((t, root));
+    x = root;
+  }
+
+  roots.resize(ts.len(), f64::NAN);
+  roots
+}
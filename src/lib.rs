@@ -1,21 +1,113 @@
+#![cfg_attr(not(feature = "std"), no_std)]
 #![warn(missing_docs)]
 #![doc = include_str!("../README.md")]
 
-pub(crate) fn compute_epsilon(a: f64, b: f64, tol: f64) -> f64 {
-  (2.0 * tol) * a.abs().max(b.abs())
+use core::cell::Cell;
+
+use generic::{constant, Float};
+
+/// Computes the absolute width below which a bracket between `a` and `b` is considered converged.
+///
+/// Tolerances below machine precision, including negative and NaN tolerances, are clamped so that the bracket is
+/// refined to machine precision instead of never converging.
+pub(crate) fn compute_epsilon<T: Float>(a: T, b: T, tol: T) -> T {
+  let tol = tol.max(constant::<T>(0.5) * T::epsilon());
+  (constant::<T>(2.0) * tol) * a.abs().max(b.abs())
+}
+
+/// Returns the interpolation step `p / q` if it is safe to take: if it is smaller than half of the step `e` taken
+/// before the previous one, and lies strictly within `(lo, hi)`. Otherwise, the caller should fall back to a more
+/// conservative step. `q` must be non-negative.
+///
+/// Requiring successive interpolation steps to halve ensures that methods built on this converge at least as fast as
+/// their fallback, while the bounds keep the new point within the current bracket.
+pub(crate) fn interpolation_step<T: Float>(p: T, q: T, e: T, lo: T, hi: T) -> Option<T> {
+  debug_assert!(q >= T::zero());
+  if p.abs() < (constant::<T>(0.5) * q * e).abs() && p > q * lo && p < q * hi {
+    Some(p / q)
+  } else {
+    None
+  }
+}
+
+/// An error arising from the inputs to a method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+  /// An input coordinate, or a known function value at it, was NaN or infinite.
+  NonFinite,
+  /// The ends of a bracket were equal, so it cannot contain a sign change.
+  ZeroWidth,
+  /// The lower end of a bracket was greater than its upper end.
+  NotOrdered,
+  /// The function had the same sign at both ends of a bracket, so it is not known to contain a root.
+  SameSign,
+}
+
+impl core::fmt::Display for Error {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    match self {
+      Error::NonFinite => write!(f, "an input coordinate or function value is not finite"),
+      Error::ZeroWidth => write!(f, "the bracket has zero width"),
+      Error::NotOrdered => write!(f, "the ends of the bracket are out of order"),
+      Error::SameSign => write!(
+        f,
+        "the function has the same sign at both ends of the bracket"
+      ),
+    }
+  }
 }
 
+impl core::error::Error for Error {}
+
 /// Represents an x-coordinate on a function, along with a potentially-known evaluation at that coordinate.
-pub enum MaybeEval {
+///
+/// Methods accepting a `MaybeEval` assume that it is finite, and their results are unspecified otherwise. Where a
+/// `try_` variant of a method exists, it instead checks its inputs with [`MaybeEval::is_finite`], and returns
+/// [`Error::NonFinite`] if they are not.
+///
+/// Endpoints are most easily built with [`MaybeEval::known`] and [`MaybeEval::unknown`], though methods also accept
+/// a bare `x` or an `(x, fx)` pair in their place. A `MaybeEval` is `Copy`, so the same endpoint may be passed to
+/// several methods:
+///
+/// ```
+/// use uniarity::bracket::{bisection, brent};
+/// use uniarity::MaybeEval;
+///
+/// let f = |x: f64| x * x - 2.0;
+/// let a = MaybeEval::known(1.0, f(1.0));
+/// let b = MaybeEval::unknown(2.0);
+/// assert!(a.is_known() && !b.is_known());
+///
+/// let x = bisection(&f, a, b, 1e-12);
+/// let y = brent(&f, &a, &b, 1e-12);
+/// assert!((x - y).abs() < 1e-11);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MaybeEval<T = f64> {
   /// The function value at this coordinate is known.
-  Known(f64, f64),
+  Known(T, T),
   /// The function value at this coordinate is unknown.
-  Unknown(f64),
+  Unknown(T),
 }
 
-impl MaybeEval {
+impl<T: Float> MaybeEval<T> {
+  /// An x-coordinate along with the known function value there.
+  pub fn known(x: T, fx: T) -> Self {
+    MaybeEval::Known(x, fx)
+  }
+
+  /// An x-coordinate at which the function value is not yet known.
+  pub fn unknown(x: T) -> Self {
+    MaybeEval::Unknown(x)
+  }
+
+  /// Whether the function value at this coordinate is known.
+  pub fn is_known(&self) -> bool {
+    matches!(self, MaybeEval::Known(..))
+  }
+
   /// Just the x-coordinate, regardless of whether the evaluation is known or not
-  pub fn x(&self) -> f64 {
+  pub fn x(&self) -> T {
     match *self {
       MaybeEval::Known(x, _) => x,
       MaybeEval::Unknown(x) => x,
@@ -23,17 +115,34 @@ impl MaybeEval {
   }
 
   /// Just the evaluated coordinate
-  pub fn fx(&self) -> Option<f64> {
+  pub fn fx(&self) -> Option<T> {
     match *self {
       MaybeEval::Known(_, fx) => Some(fx),
       MaybeEval::Unknown(_) => None,
     }
   }
 
+  /// Whether the x-coordinate, and the function value if it is known, are both finite.
+  pub fn is_finite(&self) -> bool {
+    match *self {
+      MaybeEval::Known(x, fx) => x.is_finite() && fx.is_finite(),
+      MaybeEval::Unknown(x) => x.is_finite(),
+    }
+  }
+
+  /// Returns the `MaybeEval` if it is finite, or [`Error::NonFinite`] otherwise.
+  pub(crate) fn finite(self) -> Result<Self, Error> {
+    if self.is_finite() {
+      Ok(self)
+    } else {
+      Err(Error::NonFinite)
+    }
+  }
+
   /// Returns either the known function evaluation, or, if it is unknown, computes it with the given function.
-  pub fn evaled<F>(&self, f: F) -> (f64, f64)
+  pub fn evaled<F>(&self, f: F) -> (T, T)
   where
-    F: Fn(f64) -> f64,
+    F: Fn(T) -> T,
   {
     match *self {
       MaybeEval::Known(x, fx) => (x, fx),
@@ -42,19 +151,102 @@ impl MaybeEval {
   }
 }
 
-impl From<f64> for MaybeEval {
+// A blanket `From<T>` would also convert an `(x, fx)` pair into a `MaybeEval<(f64, f64)>`, leaving
+// `MaybeEval::from((x, fx))` ambiguous, so bare coordinates convert only from the primitive float types
+impl From<f64> for MaybeEval<f64> {
   fn from(value: f64) -> Self {
     Self::Unknown(value)
   }
 }
 
-impl From<(f64, f64)> for MaybeEval {
-  fn from(value: (f64, f64)) -> Self {
+impl From<f32> for MaybeEval<f32> {
+  fn from(value: f32) -> Self {
+    Self::Unknown(value)
+  }
+}
+
+impl<T> From<(T, T)> for MaybeEval<T> {
+  fn from(value: (T, T)) -> Self {
     Self::Known(value.0, value.1)
   }
 }
 
+impl<T: Copy> From<&MaybeEval<T>> for MaybeEval<T> {
+  fn from(value: &MaybeEval<T>) -> Self {
+    *value
+  }
+}
+
+/// A function which counts its evaluations, such as to compare how many times different methods evaluate it.
+///
+/// Stable Rust does not allow implementing `Fn` for a type, so the counted function is obtained with
+/// [`Counted::as_fn`], which may be passed to any method in place of the original:
+///
+/// ```
+/// use uniarity::bracket::{bisection, bisection_iterations};
+/// use uniarity::Counted;
+///
+/// let f = Counted::new(|x: f64| x * x - 2.0);
+/// bisection(&f.as_fn(), 0.0, 2.0, 1e-15);
+/// assert_eq!(f.count(), bisection_iterations(0.0, 2.0, 1e-15) + 1);
+/// ```
+#[derive(Debug)]
+pub struct Counted<F> {
+  f: F,
+  count: Cell<usize>,
+}
+
+impl<F> Counted<F>
+where
+  F: Fn(f64) -> f64,
+{
+  /// Wraps `f`, with no evaluations counted.
+  pub fn new(f: F) -> Self {
+    Self {
+      f,
+      count: Cell::new(0),
+    }
+  }
+
+  /// Returns `f(x)`, counting the evaluation.
+  pub fn eval(&self, x: f64) -> f64 {
+    self.count.set(self.count.get() + 1);
+    (self.f)(x)
+  }
+
+  /// Returns a function which may be passed to any method in place of `f`, counting each evaluation.
+  pub fn as_fn(&self) -> impl Fn(f64) -> f64 + '_ {
+    move |x| self.eval(x)
+  }
+
+  /// The number of evaluations counted.
+  pub fn count(&self) -> usize {
+    self.count.get()
+  }
+
+  /// Resets the count to zero, so that the same function may be counted again under another method.
+  pub fn reset(&self) {
+    self.count.set(0);
+  }
+}
+
 pub mod bracket;
+#[cfg(feature = "std")]
+pub mod cache;
+#[cfg(feature = "std")]
 pub mod cheb;
+pub mod consts;
+pub mod diff;
+pub mod generic;
 pub mod initial;
+#[cfg(feature = "std")]
 pub mod min;
+#[cfg(feature = "std")]
+pub mod pchip;
+#[cfg(feature = "std")]
+pub mod piecewise;
+#[cfg(feature = "std")]
+pub mod stream;
+
+#[cfg(feature = "std")]
+pub use cache::Memoized;
@@ -1,8 +1,11 @@
-//! Methods to determine the minimum of a given function within a given range or bracket.
+//! Methods to determine the minimum of a given function within a given range or bracket, and likewise the maximum.
 
 use ordered_float::OrderedFloat;
+use std::collections::VecDeque;
 
-use crate::compute_epsilon;
+use crate::consts::{GOLDEN_RATIO_CONJUGATE, GOLDEN_SECTION};
+use crate::generic::{constant, Float};
+use crate::{compute_epsilon, interpolation_step};
 
 /// Samples `n` points along the function, and returns the point with the minimum value.
 pub fn min_by_inspection<F>(f: &F, a: f64, b: f64, n: usize) -> (f64, f64)
@@ -19,109 +22,546 @@ where
     .unwrap()
 }
 
-// TODO: Provide golden section search as an additional method, as in bracket::locate_negative
-// TODO: It may be more useful for Brent's method to take a triplet as a bracket
+/// Samples `n` points along the function, and returns the point with the maximum value, as in
+/// [`min_by_inspection`].
+pub fn max_by_inspection<F>(f: &F, a: f64, b: f64, n: usize) -> (f64, f64)
+where
+  F: Fn(f64) -> f64,
+{
+  let (x, fx) = min_by_inspection(&|x| -f(x), a, b, n);
+  (x, -fx)
+}
+
+/// Samples `n` points along the function, distributed with a density proportional to `weight`, and returns the
+/// point with the minimum value.
+/// Panics if `weight` is negative or non-finite anywhere it is sampled, or if it integrates to zero.
+///
+/// The weight need not be normalized: it is integrated over `[a, b]` with the trapezoidal rule on a fine uniform
+/// grid, and divided by its total to form a cumulative distribution function, which is linear between the grid
+/// points. The samples are placed at the evenly-spaced quantiles `i / (n - 1)` of this distribution, by inverting
+/// it, so a constant weight reproduces the samples of [`min_by_inspection`], including both ends of the interval.
+pub fn min_by_inspection_weighted<F, W>(f: &F, a: f64, b: f64, n: usize, weight: &W) -> (f64, f64)
+where
+  F: Fn(f64) -> f64,
+  W: Fn(f64) -> f64,
+{
+  let m = (16 * n).max(1024);
+  let h = (b - a) / m as f64;
+
+  let mut cdf = Vec::with_capacity(m + 1);
+  cdf.push(0.0);
+  let mut w0 = weight(a);
+  for j in 1..=m {
+    let w1 = weight(a + j as f64 * h);
+    assert!(w0 >= 0.0 && w0.is_finite() && w1 >= 0.0 && w1.is_finite());
+    cdf.push(cdf[j - 1] + 0.5 * (w0 + w1) * h);
+    w0 = w1;
+  }
+
+  let total = cdf[m];
+  assert!(total > 0.0);
+
+  let mut j = 0;
+  (0..n)
+    .map(|i| {
+      // The first grid point at which the distribution reaches the quantile
+      let target = total * i as f64 / (n - 1) as f64;
+      while j < m && cdf[j] < target {
+        j += 1;
+      }
+
+      let x = if j == 0 {
+        a
+      } else {
+        let fraction = (target - cdf[j - 1]) / (cdf[j] - cdf[j - 1]);
+        a + (j as f64 - 1.0 + fraction) * h
+      };
+      (x, f(x))
+    })
+    .min_by_key(|&(_, fx)| OrderedFloat(fx))
+    .unwrap()
+}
+
+/// Evaluates `f` at each point of `grid`, which need not be uniformly spaced, and refines the lowest point with
+/// [`min`] on the bracket formed by its neighbors.
+/// Panics if `grid` is empty or not strictly increasing.
+///
+/// When the lowest point is at an end of the grid, the bracket is formed by that end and its only neighbor. The
+/// lower of the refined minimum and the lowest grid point is returned, so refinement never makes the result worse.
+pub fn min_over_grid_then_refine<F>(f: &F, grid: &[f64], tol: f64) -> (f64, f64)
+where
+  F: Fn(f64) -> f64,
+{
+  assert!(!grid.is_empty());
+  assert!(grid.windows(2).all(|w| w[0] < w[1]));
+
+  let (i, fx) = grid
+    .iter()
+    .map(|&x| f(x))
+    .enumerate()
+    .min_by_key(|&(_, fx)| OrderedFloat(fx))
+    .unwrap();
+
+  if grid.len() == 1 {
+    return (grid[0], fx);
+  }
+
+  let lo = grid[i.saturating_sub(1)];
+  let hi = grid[(i + 1).min(grid.len() - 1)];
+  let refined = min(f, lo, hi, tol);
+
+  if refined.1 <= fx {
+    refined
+  } else {
+    (grid[i], fx)
+  }
+}
+
+/// Returns the minimum of a function within the given bracket, using golden section search.
+/// Requires that `f` is unimodal on the bracket.
+///
+/// Each iteration shrinks the bracket to `1 / phi` of its width, keeping one of the two interior points, so only
+/// one new point is evaluated. This converges linearly, unlike the parabolic steps of [`min`], but never overshoots:
+/// each step depends only on which of two values is lower, which suits noisy functions on which a parabola fit to
+/// nearby points is unreliable. Iteration stops once the width of the bracket is at most `2 * tol` times the larger
+/// magnitude of its initial ends, and the lower interior point is returned. Tolerances below machine precision
+/// refine the bracket to machine precision.
+pub fn golden_section<F>(f: &F, a: f64, b: f64, tol: f64) -> (f64, f64)
+where
+  F: Fn(f64) -> f64,
+{
+  let (mut a, mut b) = (a.min(b), a.max(b));
+  let epsilon = compute_epsilon(a, b, tol);
+
+  let mut c = b - (b - a) * GOLDEN_RATIO_CONJUGATE;
+  let mut d = a + (b - a) * GOLDEN_RATIO_CONJUGATE;
+  let (mut fc, mut fd) = (f(c), f(d));
+
+  while b - a > epsilon {
+    if fc < fd {
+      (b, d, fd) = (d, c, fc);
+      c = b - (b - a) * GOLDEN_RATIO_CONJUGATE;
+      fc = f(c);
+    } else {
+      (a, c, fc) = (c, d, fd);
+      d = a + (b - a) * GOLDEN_RATIO_CONJUGATE;
+      fd = f(d);
+    }
+  }
+
+  if fc < fd {
+    (c, fc)
+  } else {
+    (d, fd)
+  }
+}
 
 /// Returns the minimum of a function within the given bracket. This implementation uses Brent's algorithm, as described in this [paper].
 ///
 /// [paper]: https://phys.uri.edu/nigh/NumRec/bookfpdf/f10-2.pdf
-#[allow(clippy::collapsible_else_if)]
 pub fn min<F>(f: &F, a: f64, b: f64, tol: f64) -> (f64, f64)
 where
   F: Fn(f64) -> f64,
 {
-  let ax = a;
-  let cx = b;
-  let bx = 0.5 * (a + b);
-
-  let tol = compute_epsilon(a, b, tol);
-  let c_gold = 0.3819660112501052; // (phi - 1)^2
-  let z_eps = 1e-10;
-
-  let mut d = 0.0;
-
-  let mut a = ax.min(cx);
-  let mut b = ax.max(cx);
-  let mut v = bx;
-  let mut w = v;
-  let mut x = v;
-  let mut e = 0.0_f64;
-  let mut fx = f(x);
-  let mut fv = fx;
-  let mut fw = fx;
+  min_with_init(f, a, b, 0.5, tol)
+}
+
+/// Returns the minimum of a function within the bracketing triplet `ax < bx < cx`, where `f(bx)` is below both
+/// `f(ax)` and `f(cx)`, using Brent's algorithm as in [`min`].
+/// Panics if the triplet is not in increasing order.
+///
+/// Brent's algorithm starts from `bx`, rather than from the midpoint as in [`min`], so a triplet already known
+/// from bracketing, such as by [`crate::bracket::bracket_min_parabolic`], is not rediscovered. The closer `bx` is
+/// to the minimum, the sooner the parabolic steps take over.
+pub fn min_from_bracket<F>(f: &F, ax: f64, bx: f64, cx: f64, tol: f64) -> (f64, f64)
+where
+  F: Fn(f64) -> f64,
+{
+  assert!(ax < bx && bx < cx);
+  let brent = converged_brent(f, ax, cx, bx, tol);
+  (brent.x, brent.fx)
+}
+
+/// Returns the maximum of a function within the given bracket, as `(x, f(x))`, using Brent's algorithm as in [`min`]
+/// on the negated function. Negation is exact, so `f(x)` is the true value of the function at the maximizer.
+pub fn max<F>(f: &F, a: f64, b: f64, tol: f64) -> (f64, f64)
+where
+  F: Fn(f64) -> f64,
+{
+  let (x, fx) = min(&|x| -f(x), a, b, tol);
+  (x, -fx)
+}
+
+/// Returns the minimum of a function within the given bracket as in [`min`], but also stops once the lowest value
+/// found has improved by at most `f_tol` over the last 5 iterations.
+///
+/// Near a flat minimum, `f` changes by only about `f'' dx^2 / 2` across a bracket of width `dx`, so the value settles
+/// to within `f_tol` long before the bracket narrows to `tol`, and the evaluations spent narrowing it further do not
+/// improve the value. Whichever of the two criteria is met first ends the iteration. The returned value is then
+/// typically within a small multiple of `f_tol` of the minimum, as the improvements shrink steadily, but its location
+/// is only as precise as the width of the bracket at that point, which may be far wider than `tol`. The stall test
+/// also counts iterations which narrow the bracket without improving the value, such as early golden section steps,
+/// so a too-large `f_tol` may stop before the minimum is approached. A negative `f_tol` disables the stall test,
+/// reproducing [`min`].
+pub fn min_with<F>(f: &F, a: f64, b: f64, tol: f64, f_tol: f64) -> (f64, f64)
+where
+  F: Fn(f64) -> f64,
+{
+  const STALL_ITERATIONS: usize = 5;
+
+  let x = 0.5 * (a + b);
+  let mut brent = Brent::new(a, b, (x, f(x)), compute_epsilon(a, b, tol));
+
+  // The lowest value before each of the last iterations, oldest first
+  let mut history = VecDeque::with_capacity(STALL_ITERATIONS);
+  while !brent.converged() {
+    if history.len() == STALL_ITERATIONS {
+      history.pop_front();
+    }
+    history.push_back(brent.fx);
+
+    brent.step(f);
+
+    if history.len() == STALL_ITERATIONS && history[0] - brent.fx <= f_tol {
+      break;
+    }
+  }
+
+  (brent.x, brent.fx)
+}
+
+/// Returns the minimum of a function within the given bracket as in [`min`], but starts Brent's algorithm from the
+/// interior point `a + init_frac * (b - a)` rather than the midpoint.
+/// Panics if `init_frac` is not strictly between 0 and 1.
+///
+/// The first steps of the algorithm are golden section steps into the larger side of the bracket around this point,
+/// until enough points are known to fit parabolas. Starting near the minimum of an asymmetric function, such as one
+/// which rises steeply on one side and gently on the other, lets those steps land close to the minimum, so that
+/// parabolic steps take over sooner and fewer iterations are needed. Starting far from the minimum has the opposite
+/// effect.
+pub fn min_with_init<F>(f: &F, a: f64, b: f64, init_frac: f64, tol: f64) -> (f64, f64)
+where
+  F: Fn(f64) -> f64,
+{
+  assert!(0.0 < init_frac && init_frac < 1.0);
+  let brent = converged_brent(f, a, b, a + init_frac * (b - a), tol);
+
+  // fx is only ever updated alongside x, so it is the value at the returned point
+  (brent.x, brent.fx)
+}
+
+/// Whether the point returned by [`min_verified`] was confirmed to be a minimum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MinQuality {
+  /// Whether `f` is no lower on either side of the returned point, and higher on at least one side.
+  pub is_minimum: bool,
+}
+
+/// Returns the minimum of a function within the given bracket as in [`min`], as an `(x, f(x), quality)` triple,
+/// checking that `f` does not decrease to either side of the returned point.
+///
+/// `f` is evaluated at a distance `h` to each side of `x`, where `h` is the larger of eight times the tolerance to
+/// which `x` was located, so that the true minimum lies well within `h` of `x`, and `sqrt(eps) * (b - a)`, so that
+/// the change in `f` over `h` is not swamped by rounding. A side beyond the bracket is not checked, so a minimum at
+/// an end of the bracket is confirmed if `f` increases into the bracket.
+///
+/// A point with equal values to both sides, such as any point of a constant function, is reported as not a minimum,
+/// since `f` is flat there rather than having a distinct minimum.
+pub fn min_verified<F>(f: &F, a: f64, b: f64, tol: f64) -> (f64, f64, MinQuality)
+where
+  F: Fn(f64) -> f64,
+{
+  let brent = converged_brent(f, a, b, 0.5 * (a + b), tol);
+  let (x, fx) = (brent.x, brent.fx);
+
+  let (lo, hi) = (a.min(b), a.max(b));
+  let h = (8.0 * brent.tol1()).max(f64::EPSILON.sqrt() * (hi - lo));
+  let side = |y: f64| (lo..=hi).contains(&y).then(|| f(y));
+  let (left, right) = (side(x - h), side(x + h));
+
+  let no_lower = left.is_none_or(|fl| fl >= fx) && right.is_none_or(|fr| fr >= fx);
+  let higher = left.is_some_and(|fl| fl > fx) || right.is_some_and(|fr| fr > fx);
+
+  (
+    x,
+    fx,
+    MinQuality {
+      is_minimum: no_lower && higher,
+    },
+  )
+}
+
+/// Returns the minimum of a function within the given bracket as in [`min`], as an `(x, f(x), f''(x))` triple, with
+/// the second derivative estimated at the minimizer.
+///
+/// The second derivative is estimated by the central difference `(f(x + h) - 2f(x) + f(x - h)) / h^2`, reusing the
+/// value of `f(x)` found by the minimization, so only two further evaluations are needed. The points Brent's
+/// algorithm evaluated last lie within its tolerance of `x`, which is too close for a difference. The step is
+/// instead `h = eps^(1/4) * max(|x|, 1)`. This balances a truncation error of about `h^2 |f''''| / 12` against a
+/// rounding error of about `4 eps |f(x)| / h^2`, so the estimate is accurate to about `sqrt(eps)` relative to the
+/// scale of `f`. A small `f''` indicates a flat minimum, whose location is ill-determined: a change of `df` in `f`
+/// moves it by about `sqrt(2 df / f'')`.
+///
+/// The estimate is NaN if `x` lies within `h` of an end of the bracket, such as when the minimum lies at an end, where
+/// Brent's algorithm stops just inside it. A smaller step there would be dominated by rounding error, and the
+/// minimum of `f` within the bracket need not be a stationary point at all.
+pub fn min_with_curvature<F>(f: &F, a: f64, b: f64, tol: f64) -> (f64, f64, f64)
+where
+  F: Fn(f64) -> f64,
+{
+  let brent = converged_brent(f, a, b, 0.5 * (a + b), tol);
+  let (x, fx) = (brent.x, brent.fx);
+
+  let (lo, hi) = (a.min(b), a.max(b));
+  let h = f64::EPSILON.powf(0.25) * x.abs().max(1.0);
+  if x - lo < h || hi - x < h {
+    return (x, fx, f64::NAN);
+  }
+
+  (x, fx, (f(x + h) - 2.0 * fx + f(x - h)) / (h * h))
+}
+
+/// Runs Brent's algorithm on the bracket `[a, b]`, starting from the interior point `x`, until it converges.
+pub(crate) fn converged_brent<T, F>(f: &F, a: T, b: T, x: T, tol: T) -> Brent<T>
+where
+  T: Float,
+  F: Fn(T) -> T,
+{
+  let mut brent = Brent::new(a, b, (x, f(x)), compute_epsilon(a, b, tol));
 
   // TODO: Not forever
-  loop {
-    let xm = 0.5 * (a + b);
-    let tol1 = tol * x.abs() + z_eps;
-    let tol2 = 2.0 * tol1;
+  while !brent.converged() {
+    brent.step(f);
+  }
 
-    if (x - xm).abs() <= tol2 - 0.5 * (b - a) {
-      return (x, f(x));
+  brent
+}
+
+/// Tracks the minimum of a slowly-changing function across repeated calls, such as once per frame of an animation.
+///
+/// Rather than converging fully on each call, [`IncrementalMinimizer::update`] takes a bounded number of iterations
+/// of Brent's algorithm, warm-started from the previous minimum within a bracket around it. This spreads the cost of
+/// locating the minimum across calls, at the price of lag: when the minimum moves further between calls than the
+/// iterations allowed can follow, the reported minimum trails behind it.
+///
+/// The bracket around the previous minimum grows with how far the minimum moved on the last call. If the minimum
+/// appears to have left the bracket entirely, as when the function changes drastically, the search restarts on the
+/// full interval.
+pub struct IncrementalMinimizer {
+  lo: f64,
+  hi: f64,
+  iterations: usize,
+  x: f64,
+  fx: f64,
+  radius: f64,
+}
+
+impl IncrementalMinimizer {
+  /// Begins tracking a minimum within the interval `[lo, hi]`, taking at most `iterations` iterations per update.
+  /// Panics if `lo > hi`.
+  pub fn new(lo: f64, hi: f64, iterations: usize) -> Self {
+    assert!(lo <= hi);
+    Self {
+      lo,
+      hi,
+      iterations,
+      x: 0.5 * (lo + hi),
+      fx: f64::NAN,
+      radius: 0.5 * (hi - lo),
+    }
+  }
+
+  /// The minimum found by the last update, as an `(x, f(x))` pair. The value is `NaN` before the first update.
+  pub fn minimum(&self) -> (f64, f64) {
+    (self.x, self.fx)
+  }
+
+  /// Forgets the previous minimum, so that the next update searches the full interval.
+  pub fn reset(&mut self) {
+    self.x = 0.5 * (self.lo + self.hi);
+    self.radius = 0.5 * (self.hi - self.lo);
+  }
+
+  /// Refines the minimum for the current function `f`, returning it as an `(x, f(x))` pair.
+  /// Each update evaluates `f` at most `iterations + 1` times, or twice that if the search restarts.
+  pub fn update<F>(&mut self, f: &F, tol: f64) -> (f64, f64)
+  where
+    F: Fn(f64) -> f64,
+  {
+    let previous = self.x;
+    let a = (previous - self.radius).max(self.lo);
+    let b = (previous + self.radius).min(self.hi);
+    let mut brent = self.search(f, a, b, tol);
+
+    // The minimum has run into an edge of the bracket which is not an edge of the interval
+    let reach = 0.9 * self.radius;
+    if (brent.x <= previous - reach && a > self.lo) || (brent.x >= previous + reach && b < self.hi)
+    {
+      brent = self.search(f, self.lo, self.hi, tol);
     }
 
+    let epsilon = compute_epsilon(self.lo, self.hi, tol);
+    self.radius = 2.0
+      * (brent.x - previous)
+        .abs()
+        .max(brent.b - brent.a)
+        .max(epsilon);
+    (self.x, self.fx) = (brent.x, brent.fx);
+    (self.x, self.fx)
+  }
+
+  fn search<F>(&self, f: &F, a: f64, b: f64, tol: f64) -> Brent
+  where
+    F: Fn(f64) -> f64,
+  {
+    let x = self.x.clamp(a, b);
+    let mut brent = Brent::new(a, b, (x, f(x)), compute_epsilon(a, b, tol));
+    for _ in 0..self.iterations {
+      if brent.converged() {
+        break;
+      }
+      brent.step(f);
+    }
+    brent
+  }
+}
+
+/// The state of Brent's algorithm for minimization, as used by [`min`].
+pub(crate) struct Brent<T = f64> {
+  /// The bracket around the minimum.
+  a: T,
+  b: T,
+  /// The lowest point found, the second lowest, and the previous value of `w`.
+  pub(crate) x: T,
+  w: T,
+  v: T,
+  pub(crate) fx: T,
+  fw: T,
+  fv: T,
+  /// The step taken on the last iteration, and on the one before that.
+  d: T,
+  e: T,
+  tol: T,
+}
+
+impl<T: Float> Brent<T> {
+  const Z_EPS: f64 = 1e-10;
+
+  /// Begins Brent's algorithm on the bracket `[a, b]`, from a known point `x` within it.
+  fn new(a: T, b: T, (x, fx): (T, T), tol: T) -> Self {
+    Self {
+      a: a.min(b),
+      b: a.max(b),
+      x,
+      w: x,
+      v: x,
+      fx,
+      fw: fx,
+      fv: fx,
+      d: T::zero(),
+      e: T::zero(),
+      tol,
+    }
+  }
+
+  fn tol1(&self) -> T {
+    self.tol * self.x.abs() + constant(Self::Z_EPS)
+  }
+
+  /// Whether `x` has been located to within the tolerance.
+  fn converged(&self) -> bool {
+    let half = constant::<T>(0.5);
+    let xm = half * (self.a + self.b);
+    (self.x - xm).abs() <= constant::<T>(2.0) * self.tol1() - half * (self.b - self.a)
+  }
+
+  /// Performs a single iteration, evaluating `f` once.
+  #[allow(clippy::collapsible_else_if)]
+  fn step<F>(&mut self, f: &F)
+  where
+    F: Fn(T) -> T,
+  {
+    let two = constant::<T>(2.0);
+    let xm = constant::<T>(0.5) * (self.a + self.b);
+    let tol1 = self.tol1();
+    let tol2 = two * tol1;
+
+    let Self {
+      a,
+      b,
+      x,
+      w,
+      v,
+      fx,
+      fw,
+      fv,
+      d,
+      e,
+      ..
+    } = self;
+
     if e.abs() > tol1 {
-      let r = (x - w) * (fx - fv);
-      let mut q = (x - v) * (fx - fw);
-      let mut p = (x - v) * q - (x - w) * r;
+      let r = (*x - *w) * (*fx - *fv);
+      let mut q = (*x - *v) * (*fx - *fw);
+      let mut p = (*x - *v) * q - (*x - *w) * r;
 
-      q = 2.0 * (q - r);
-      if q > 0.0 {
+      q = two * (q - r);
+      if q > T::zero() {
         p = -p;
       } else {
         q = -q;
       }
 
-      let e_prev = e;
-      e = d;
-      if p.abs() >= (0.5 * q * e_prev).abs() || p <= q * (a - x) || p >= q * (b - x) {
-        e = if x >= xm { a - x } else { b - x };
-        d = c_gold * e;
-      } else {
-        d = p / q;
-        let u = x + d;
-        if u - a < tol2 || b - u < tol2 {
-          d = tol1.copysign(xm - x);
+      let e_prev = *e;
+      *e = *d;
+      if let Some(step) = interpolation_step(p, q, e_prev, *a - *x, *b - *x) {
+        *d = step;
+        let u = *x + *d;
+        if u - *a < tol2 || *b - u < tol2 {
+          *d = tol1.copysign(xm - *x);
         }
+      } else {
+        *e = if *x >= xm { *a - *x } else { *b - *x };
+        *d = constant::<T>(GOLDEN_SECTION) * *e;
       }
     } else {
-      e = if x >= xm { a - x } else { b - x };
-      d = c_gold * e;
+      *e = if *x >= xm { *a - *x } else { *b - *x };
+      *d = constant::<T>(GOLDEN_SECTION) * *e;
     }
 
     let u = if d.abs() >= tol1 {
-      x + d
+      *x + *d
     } else {
-      x + tol1.copysign(d)
+      *x + tol1.copysign(*d)
     };
 
     let fu = f(u);
 
-    if fu <= fx {
-      if u >= x {
-        a = x;
+    if fu <= *fx {
+      if u >= *x {
+        *a = *x;
       } else {
-        b = x;
+        *b = *x;
       }
 
-      (v, w, x) = (w, x, u);
-      (fv, fw, fx) = (fw, fx, fu);
+      (*v, *w, *x) = (*w, *x, u);
+      (*fv, *fw, *fx) = (*fw, *fx, fu);
     } else {
-      if u < x {
-        a = u;
+      if u < *x {
+        *a = u;
       } else {
-        b = u;
+        *b = u;
       }
     }
 
-    if fu <= fw || w == x {
-      v = w;
-      fv = fw;
-      w = u;
-      fw = fu;
-    } else if fu <= fv || v == x || v == w {
-      v = u;
-      fv = fu;
+    if fu <= *fw || *w == *x {
+      *v = *w;
+      *fv = *fw;
+      *w = u;
+      *fw = fu;
+    } else if fu <= *fv || *v == *x || *v == *w {
+      *v = u;
+      *fv = fu;
     }
   }
 }
@@ -0,0 +1,217 @@
+//! Shape-preserving piecewise cubic Hermite interpolation (PCHIP) of tabulated data.
+//!
+//! Unlike a Chebyshev approximation, which oscillates near discontinuities and kinks, a PCHIP interpolant never
+//! overshoots the data: it is monotone wherever the data is monotone, and has its local extrema only at the data
+//! points. This makes it the better choice for noisy or monotone tabulated data.
+//!
+//! The derivatives at the data points follow [Fritsch and Carlson], using a weighted harmonic mean of the adjacent
+//! secant slopes, with one-sided shape-preserving estimates at the endpoints.
+//!
+//! [Fritsch and Carlson]: https://doi.org/10.1137/0717021
+
+use std::f64::consts::TAU;
+
+/// A shape-preserving piecewise cubic interpolant of tabulated data.
+pub struct PchipInterp {
+  xs: Vec<f64>,
+  ys: Vec<f64>,
+  ds: Vec<f64>,
+}
+
+impl PchipInterp {
+  /// Constructs an interpolant through the points `(xs[i], ys[i])`.
+  /// Panics if `xs` and `ys` differ in length, if there are fewer than two points, or if `xs` is not strictly
+  /// increasing.
+  pub fn new(xs: &[f64], ys: &[f64]) -> Self {
+    assert_eq!(xs.len(), ys.len());
+    assert!(xs.len() >= 2);
+    assert!(xs.windows(2).all(|w| w[0] < w[1]));
+
+    let n = xs.len();
+    let h: Vec<f64> = xs.windows(2).map(|w| w[1] - w[0]).collect();
+    let delta: Vec<f64> = (0..n - 1).map(|k| (ys[k + 1] - ys[k]) / h[k]).collect();
+
+    let mut ds = vec![0.0; n];
+    if n == 2 {
+      ds[0] = delta[0];
+      ds[1] = delta[0];
+    } else {
+      for k in 1..n - 1 {
+        // The derivative is zero at local extrema of the data, which keeps each segment monotone
+        if delta[k - 1].signum() != delta[k].signum() || delta[k - 1] == 0.0 || delta[k] == 0.0 {
+          continue;
+        }
+
+        let w1 = 2.0 * h[k] + h[k - 1];
+        let w2 = h[k] + 2.0 * h[k - 1];
+        ds[k] = (w1 + w2) / (w1 / delta[k - 1] + w2 / delta[k]);
+      }
+
+      ds[0] = end_derivative(h[0], h[1], delta[0], delta[1]);
+      ds[n - 1] = end_derivative(h[n - 2], h[n - 3], delta[n - 2], delta[n - 3]);
+    }
+
+    Self {
+      xs: xs.to_vec(),
+      ys: ys.to_vec(),
+      ds,
+    }
+  }
+
+  /// Returns the cubic coefficients of segment `k` in the local coordinate `t = x - xs[k]`, lowest order first.
+  fn segment(&self, k: usize) -> [f64; 4] {
+    let h = self.xs[k + 1] - self.xs[k];
+    let delta = (self.ys[k + 1] - self.ys[k]) / h;
+    let (d0, d1) = (self.ds[k], self.ds[k + 1]);
+
+    [
+      self.ys[k],
+      d0,
+      (3.0 * delta - 2.0 * d0 - d1) / h,
+      (d0 + d1 - 2.0 * delta) / (h * h),
+    ]
+  }
+
+  /// Evaluates the interpolant at a given x-value.
+  ///
+  /// Outside of the range of the data, the interpolant is extrapolated linearly using the value and derivative at
+  /// the nearest endpoint, which preserves monotonicity. Returns `NaN` if `x` is `NaN`.
+  pub fn evaluate(&self, x: f64) -> f64 {
+    if x.is_nan() {
+      return f64::NAN;
+    }
+
+    let n = self.xs.len();
+    if x <= self.xs[0] {
+      return self.ys[0] + self.ds[0] * (x - self.xs[0]);
+    }
+    if x >= self.xs[n - 1] {
+      return self.ys[n - 1] + self.ds[n - 1] * (x - self.xs[n - 1]);
+    }
+
+    // The segment k such that xs[k] <= x < xs[k + 1]
+    let k = self.xs.partition_point(|&xk| xk <= x) - 1;
+    let [c0, c1, c2, c3] = self.segment(k);
+    let t = x - self.xs[k];
+    c0 + t * (c1 + t * (c2 + t * c3))
+  }
+
+  /// Returns all roots of the interpolant within the range of the data, in increasing order.
+  ///
+  /// Each segment is monotone, so it contains at most one root, which is found by solving its cubic analytically.
+  /// Segments which are identically zero contribute only their endpoints. Roots of the linear extrapolation outside
+  /// the range of the data are not reported.
+  pub fn roots(&self) -> Vec<f64> {
+    let mut roots = Vec::new();
+
+    for k in 0..self.xs.len() - 1 {
+      let (y0, y1) = (self.ys[k], self.ys[k + 1]);
+
+      if y0 == 0.0 {
+        roots.push(self.xs[k]);
+      } else if y1 != 0.0 && y0.signum() != y1.signum() {
+        let h = self.xs[k + 1] - self.xs[k];
+        let [c0, c1, c2, c3] = self.segment(k);
+
+        // Solve in the normalized coordinate s = t / h, which lies in [0, 1]
+        let coefficients = [c0, c1 * h, c2 * h * h, c3 * h * h * h];
+        let s = cubic_roots(coefficients)
+          .into_iter()
+          .min_by(|&s1, &s2| distance_to_unit(s1).total_cmp(&distance_to_unit(s2)))
+          .map(|s| polish(coefficients, s.clamp(0.0, 1.0)))
+          .unwrap_or(0.5);
+
+        roots.push(self.xs[k] + s.clamp(0.0, 1.0) * h);
+      }
+    }
+
+    if self.ys[self.ys.len() - 1] == 0.0 {
+      roots.push(self.xs[self.xs.len() - 1]);
+    }
+
+    roots
+  }
+}
+
+/// The shape-preserving three-point estimate of the derivative at an endpoint, where `h0` and `delta0` belong to the
+/// segment adjacent to the endpoint.
+fn end_derivative(h0: f64, h1: f64, delta0: f64, delta1: f64) -> f64 {
+  let d = ((2.0 * h0 + h1) * delta0 - h0 * delta1) / (h0 + h1);
+
+  if d.signum() != delta0.signum() {
+    0.0
+  } else if delta0.signum() != delta1.signum() && d.abs() > (3.0 * delta0).abs() {
+    3.0 * delta0
+  } else {
+    d
+  }
+}
+
+/// The distance from `s` to the interval \[0, 1\].
+fn distance_to_unit(s: f64) -> f64 {
+  (s - s.clamp(0.0, 1.0)).abs()
+}
+
+/// Refines a root of the cubic with coefficients `c` (lowest order first) using a few Newton steps.
+fn polish(c: [f64; 4], mut s: f64) -> f64 {
+  for _ in 0..3 {
+    let p = c[0] + s * (c[1] + s * (c[2] + s * c[3]));
+    let dp = c[1] + s * (2.0 * c[2] + s * 3.0 * c[3]);
+    if dp == 0.0 {
+      break;
+    }
+    s -= p / dp;
+  }
+  s
+}
+
+/// Returns the real roots of the cubic polynomial with coefficients `c` (lowest order first), degrading gracefully
+/// to the quadratic and linear cases when the leading coefficients vanish.
+fn cubic_roots(c: [f64; 4]) -> Vec<f64> {
+  let [d, c1, b2, a3] = c;
+  let scale = c.iter().fold(0.0_f64, |acc, x| acc.max(x.abs()));
+
+  if a3.abs() <= 1e-12 * scale {
+    return quadratic_roots(d, c1, b2, scale);
+  }
+
+  // Normalize to s^3 + b s^2 + c s + d and substitute s = u - b / 3 to obtain u^3 + p u + q
+  let (b, c, d) = (b2 / a3, c1 / a3, d / a3);
+  let p = c - b * b / 3.0;
+  let q = 2.0 * b * b * b / 27.0 - b * c / 3.0 + d;
+  let shift = -b / 3.0;
+
+  let disc = 0.25 * q * q + p * p * p / 27.0;
+  if disc > 0.0 {
+    let sqrt_disc = disc.sqrt();
+    let u = (-0.5 * q + sqrt_disc).cbrt() + (-0.5 * q - sqrt_disc).cbrt();
+    vec![u + shift]
+  } else {
+    // Three real roots, via the trigonometric method
+    let r = 2.0 * (-p / 3.0).sqrt();
+    let theta = ((3.0 * q) / (p * r)).clamp(-1.0, 1.0).acos() / 3.0;
+    (0..3)
+      .map(|k| r * (theta - TAU * k as f64 / 3.0).cos() + shift)
+      .collect()
+  }
+}
+
+/// Returns the real roots of `c + b s + a s^2`.
+fn quadratic_roots(c: f64, b: f64, a: f64, scale: f64) -> Vec<f64> {
+  if a.abs() <= 1e-12 * scale {
+    return if b == 0.0 { vec![] } else { vec![-c / b] };
+  }
+
+  let disc = b * b - 4.0 * a * c;
+  if disc < 0.0 {
+    return vec![];
+  }
+
+  // Avoid cancellation by computing the larger-magnitude root first
+  let q = -0.5 * (b + disc.sqrt().copysign(b));
+  if q == 0.0 {
+    vec![0.0]
+  } else {
+    vec![q / a, c / q]
+  }
+}
@@ -0,0 +1,107 @@
+//! Functions defined by different expressions on different sub-intervals, such as physical models with regime
+//! changes.
+//!
+//! A [`PiecewiseFn`] may be passed to any method by evaluating it through a closure, such as
+//! `|x| piecewise.evaluate(x)`. The bracketing methods then work as usual within a single piece, but a bracket
+//! spanning a breakpoint at which the function jumps may converge onto the jump rather than a root.
+//! [`PiecewiseFn::roots`] avoids this by searching each piece separately.
+
+use crate::bracket::itp;
+
+/// A single piece of a [`PiecewiseFn`].
+type Piece = Box<dyn Fn(f64) -> f64>;
+
+/// A function defined piece by piece, with each piece applying from its breakpoint up to the next.
+pub struct PiecewiseFn {
+  /// The breakpoint at which each piece begins, in increasing order, and the piece. The first breakpoint is negative
+  /// infinity.
+  pieces: Vec<(f64, Piece)>,
+}
+
+impl PiecewiseFn {
+  /// Constructs a function consisting of a single piece, which applies everywhere until another is added.
+  pub fn new(piece: impl Fn(f64) -> f64 + 'static) -> Self {
+    Self {
+      pieces: vec![(f64::NEG_INFINITY, Box::new(piece))],
+    }
+  }
+
+  /// Adds a piece which applies from `breakpoint` onwards, including at the breakpoint itself.
+  /// Panics if `breakpoint` is not greater than every breakpoint already added.
+  pub fn with_piece(mut self, breakpoint: f64, piece: impl Fn(f64) -> f64 + 'static) -> Self {
+    assert!(breakpoint > self.pieces.last().unwrap().0);
+    self.pieces.push((breakpoint, Box::new(piece)));
+    self
+  }
+
+  /// The breakpoints between the pieces, in increasing order.
+  pub fn breakpoints(&self) -> impl Iterator<Item = f64> + '_ {
+    self
+      .pieces
+      .iter()
+      .skip(1)
+      .map(|&(breakpoint, _)| breakpoint)
+  }
+
+  /// Evaluates the function at a given x-value, using the last piece whose breakpoint is at or before it.
+  pub fn evaluate(&self, x: f64) -> f64 {
+    let k = self
+      .pieces
+      .partition_point(|&(breakpoint, _)| breakpoint <= x);
+    (self.pieces[k.saturating_sub(1)].1)(x)
+  }
+
+  /// Returns the roots of the function within \[a, b\], in increasing order.
+  /// Panics if `a > b` or if `step` is not positive.
+  ///
+  /// Each piece is scanned separately across the part of \[a, b\] it applies to, in steps of at most `step`, and each
+  /// sign change found is refined with [`itp`] on that piece alone. A sign change across a breakpoint, where the
+  /// function jumps from one piece to the next, is therefore never reported as a root. Each piece is evaluated at
+  /// the breakpoint which ends it, to detect sign changes up to the breakpoint, but a zero there is only a root of
+  /// the next piece, which applies at the breakpoint. As in [`crate::bracket::find_root_bracket`], a step which
+  /// passes over an even number of roots does not detect them.
+  pub fn roots(&self, a: f64, b: f64, step: f64, tol: f64) -> Vec<f64> {
+    assert!(a <= b);
+    assert!(step > 0.0);
+
+    let mut roots = Vec::new();
+
+    for (k, (start, piece)) in self.pieces.iter().enumerate() {
+      let end = self
+        .pieces
+        .get(k + 1)
+        .map_or(f64::INFINITY, |&(end, _)| end);
+      let (lo, hi) = (start.max(a), end.min(b));
+      if lo > hi || (lo == hi && hi == end) {
+        continue;
+      }
+
+      // Whether a zero at hi is a root of this piece, rather than one belonging to the next
+      let owns_hi = hi < end;
+
+      let (mut x, mut fx) = (lo, piece(lo));
+      if fx == 0.0 && (lo < hi || owns_hi) {
+        roots.push(lo);
+      }
+
+      let mut i = 1.0;
+      while x < hi {
+        let next = (lo + i * step).min(hi);
+        let f_next = piece(next);
+
+        if f_next == 0.0 {
+          if next < hi || owns_hi {
+            roots.push(next);
+          }
+        } else if (fx < 0.0 && f_next > 0.0) || (fx > 0.0 && f_next < 0.0) {
+          roots.push(itp(piece, (x, fx), (next, f_next), tol));
+        }
+
+        (x, fx) = (next, f_next);
+        i += 1.0;
+      }
+    }
+
+    roots
+  }
+}
@@ -0,0 +1,83 @@
+//! Root finding over a stream of samples, such as a signal sampled in real time, without buffering it.
+//!
+//! A [`ZeroCrossingDetector`] consumes samples one at a time and reports each zero crossing as soon as the sample
+//! after it arrives, so its latency is a single sample. Only the last four samples are retained.
+
+use std::collections::VecDeque;
+
+use crate::bracket::itp;
+
+/// Detects the zero crossings of a stream of `(t, value)` samples, as they arrive.
+///
+/// A crossing is detected between consecutive samples with strictly opposite signs, and located by interpolating the
+/// samples. Once four samples are available, the crossing is located on the cubic through the last four, the two
+/// straddling it and the two before them, which is accurate to `O(dt^4)` for a smooth signal sampled at spacing `dt`.
+/// Before then, the two straddling samples are interpolated linearly, which is accurate to `O(dt^2)`. Interpolating
+/// only past samples keeps the latency at one sample, at the cost of a less centered stencil. A sample which is
+/// exactly zero is reported as a crossing itself, and a signal which only touches zero between samples, or crosses
+/// it an even number of times, is not detected.
+#[derive(Debug, Clone, Default)]
+pub struct ZeroCrossingDetector {
+  /// The most recent samples, oldest first.
+  history: VecDeque<(f64, f64)>,
+}
+
+impl ZeroCrossingDetector {
+  /// Constructs a detector which has not yet received any samples.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Consumes the next sample, returning the time of the zero crossing between it and the previous sample, if any.
+  /// Panics if `t` is not greater than the time of the previous sample.
+  pub fn push(&mut self, t: f64, value: f64) -> Option<f64> {
+    if let Some(&(t0, _)) = self.history.back() {
+      assert!(t > t0);
+    }
+
+    if self.history.len() == 4 {
+      self.history.pop_front();
+    }
+    self.history.push_back((t, value));
+
+    if value == 0.0 {
+      return Some(t);
+    }
+
+    let n = self.history.len();
+    let (t0, v0) = *self.history.get(n.checked_sub(2)?)?;
+    if !((v0 < 0.0 && value > 0.0) || (v0 > 0.0 && value < 0.0)) {
+      return None;
+    }
+
+    if n < 4 {
+      return Some(t0 - v0 * (t - t0) / (value - v0));
+    }
+
+    let samples = [
+      self.history[0],
+      self.history[1],
+      self.history[2],
+      self.history[3],
+    ];
+    let cubic = |x: f64| lagrange(&samples, x);
+    Some(itp(&cubic, (t0, v0), (t, value), f64::EPSILON))
+  }
+}
+
+/// Evaluates the polynomial through the given points at `x`, in Lagrange form.
+fn lagrange(points: &[(f64, f64)], x: f64) -> f64 {
+  points
+    .iter()
+    .enumerate()
+    .map(|(i, &(xi, yi))| {
+      let basis: f64 = points
+        .iter()
+        .enumerate()
+        .filter(|&(j, _)| j != i)
+        .map(|(_, &(xj, _))| (x - xj) / (xi - xj))
+        .product();
+      yi * basis
+    })
+    .sum()
+}
@@ -0,0 +1,66 @@
+use std::cell::Cell;
+
+use uniarity::bracket::{bisection, itp};
+use uniarity::cache::EvalCache;
+use uniarity::min::min;
+use uniarity::Memoized;
+
+#[test]
+fn test_shared_evaluations() {
+  let calls = Cell::new(0);
+  let f = |x: f64| {
+    calls.set(calls.get() + 1);
+    x.cos() - x
+  };
+
+  let cache = EvalCache::new();
+  let g = cache.bind(&f);
+
+  let x1 = bisection(&g, 0.0, 1.0, 1e-15);
+  let after_first = calls.get();
+  assert_eq!(after_first, cache.len());
+
+  // The same method probes exactly the same points, so nothing is evaluated again
+  let x2 = bisection(&g, 0.0, 1.0, 1e-15);
+  assert_eq!(x1, x2);
+  assert_eq!(calls.get(), after_first);
+
+  // A different method shares at least the endpoints
+  let x3 = itp(&g, 0.0, 1.0, 1e-15);
+  assert!((x1 - x3).abs() < 1e-14);
+  assert_eq!(calls.get(), cache.len());
+
+  min(&g, -1.0, 1.0, 1e-10);
+  assert_eq!(calls.get(), cache.len());
+
+  cache.clear();
+  assert!(cache.is_empty());
+}
+
+#[test]
+fn test_memoized() {
+  let calls = Cell::new(0);
+  let f = Memoized::new(|x: f64| {
+    calls.set(calls.get() + 1);
+    x.cos() - x
+  });
+
+  // Repeating a method repeats every evaluation, so the second run is answered entirely from memory
+  let x1 = bisection(&f.as_fn(), 0.0, 1.0, 1e-15);
+  assert_eq!((f.hits(), f.misses()), (0, calls.get()));
+  let x2 = bisection(&f.as_fn(), 0.0, 1.0, 1e-15);
+  assert_eq!(x1, x2);
+  assert_eq!(f.hits(), f.misses());
+  assert_eq!(f.misses(), calls.get());
+
+  // A different method shares at least the endpoints
+  let hits = f.hits();
+  itp(&f.as_fn(), 0.0, 1.0, 1e-15);
+  assert!(f.hits() >= hits + 2);
+  assert_eq!(f.misses(), calls.get());
+
+  f.clear();
+  assert_eq!((f.hits(), f.misses()), (0, 0));
+  f.eval(0.5);
+  assert_eq!(f.misses(), 1);
+}
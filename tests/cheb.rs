@@ -2,8 +2,18 @@ use approx::{assert_abs_diff_eq, assert_relative_eq};
 use fastrand::Rng;
 use itertools::{izip, Itertools};
 
+use faer::complex::Complex;
+use faer::{Mat, MatRef};
 use ordered_float::OrderedFloat;
-use uniarity::cheb::Cheb;
+use std::cell::Cell;
+use std::cmp::Ordering;
+use std::f64::consts::PI;
+use std::time::Instant;
+use uniarity::cheb::{
+  characterize, is_monotonic, nearest_min, stationary_points, Cheb, CodeLang, CrossDirection,
+  EigenSolver, Extremum, FaerEigenSolver,
+};
+use uniarity::diff::numerical_derivative;
 
 const N_TESTS: usize = 1_000;
 
@@ -356,3 +366,1003 @@ fn test_numerically_unstable2() {
   assert!(roots.len() == 1);
   assert_abs_diff_eq!(roots[0], 5.404, epsilon = 1e-3);
 }
+
+#[test]
+fn test_characterize() {
+  let summary = characterize(&|x: f64| x.powi(3) - x, -2.0, 2.0, 8);
+  let s = 3f64.sqrt().recip();
+
+  assert_eq!(summary.roots.len(), 3);
+  for (&x, expected) in izip!(summary.roots.iter(), [-1.0, 0.0, 1.0]) {
+    assert_abs_diff_eq!(x, expected, epsilon = 1e-12);
+  }
+
+  assert_eq!(summary.minima.len(), 1);
+  assert_abs_diff_eq!(summary.minima[0].0, s, epsilon = 1e-12);
+  assert_abs_diff_eq!(summary.minima[0].1, s.powi(3) - s, epsilon = 1e-12);
+
+  assert_eq!(summary.maxima.len(), 1);
+  assert_abs_diff_eq!(summary.maxima[0].0, -s, epsilon = 1e-12);
+
+  assert_eq!(summary.inflections.len(), 1);
+  assert_abs_diff_eq!(summary.inflections[0], 0.0, epsilon = 1e-12);
+}
+
+#[test]
+fn test_stationary_points() {
+  let (a, b) = (-1.0, 7.0);
+  let points = stationary_points(&|x: f64| x.cos(), a, b, 20);
+
+  assert_eq!(points.len(), 3);
+  for (&x, expected) in izip!(points.iter(), [0.0, PI, 2.0 * PI]) {
+    assert_abs_diff_eq!(x, expected, epsilon = 1e-5 * (b - a));
+  }
+}
+
+#[test]
+fn test_root_solver() {
+  let f = |x: f64| x.sin() + 0.3 * x;
+  let (a, b) = (-6.0, 6.0);
+  let solver = Cheb::new(&f, a, b, 20).root_solver();
+
+  for y in [-0.2, 0.0, 0.7, 1.2] {
+    let expected = Cheb::new(&|x| f(x) - y, a, b, 20).roots();
+    let roots = solver.solve(y);
+
+    assert_eq!(roots.len(), expected.len());
+    for (&x, &e) in roots.iter().zip(&expected) {
+      assert_abs_diff_eq!(x, e, epsilon = 1e-7);
+      assert_abs_diff_eq!(f(x), y, epsilon = 1e-7);
+    }
+  }
+}
+
+/// Times a closure, returning its result along with the elapsed time in milliseconds.
+fn timed<T>(f: impl FnOnce() -> T) -> (T, f64) {
+  let start = Instant::now();
+  let result = f();
+  (result, 1e3 * start.elapsed().as_secs_f64())
+}
+
+// A benchmark rather than a check, so it is ignored by default. Run it in release mode with
+// `cargo test --release --test cheb -- --ignored --nocapture`
+#[test]
+#[ignore]
+fn bench_root_solver() {
+  let f = |x: f64| (10.0 * x).sin() + 0.3 * x;
+  let shifts: Vec<f64> = (0..200).map(|i| -1.0 + 0.01 * i as f64).collect();
+
+  for n in [16, 64, 256] {
+    let cheb = Cheb::new(&f, -6.0, 6.0, n);
+    let (a, b) = cheb.interval();
+
+    // Rebuilding the approximation, and with it the companion matrix, for every shift
+    let (rebuilt, rebuild_ms) = timed(|| {
+      shifts
+        .iter()
+        .map(|&delta| {
+          let mut c = cheb.coefficients().to_vec();
+          c[0] += delta;
+          Cheb::from_coefficients(a, b, c).roots()
+        })
+        .collect_vec()
+    });
+
+    // Building the companion matrix once, and only perturbing the entry of c[0]
+    let (reused, reuse_ms) = timed(|| {
+      let solver = cheb.root_solver();
+      shifts
+        .iter()
+        .map(|&delta| solver.roots_of_shifted(delta))
+        .collect_vec()
+    });
+
+    // The perturbed entry is rounded differently, so the roots agree only to within rounding
+    for (rebuilt, reused) in rebuilt.iter().zip(&reused) {
+      assert_eq!(rebuilt.len(), reused.len());
+      for (&x, &y) in rebuilt.iter().zip(reused) {
+        assert_abs_diff_eq!(x, y, epsilon = 1e-8);
+      }
+    }
+    println!(
+      "degree {:3}: rebuilt {rebuild_ms:8.2} ms, reused {reuse_ms:8.2} ms, speedup {:.2}x",
+      cheb.degree(),
+      rebuild_ms / reuse_ms
+    );
+  }
+}
+
+#[test]
+fn test_roots_into() {
+  let mut out = vec![f64::NAN; 100];
+
+  for n in 1..30 {
+    let cheb = Cheb::new(&|x: f64| (3.0 * x).cos(), -4.0, 4.0, n);
+    cheb.roots_into(&mut out);
+    assert_eq!(out, cheb.roots());
+  }
+}
+
+#[test]
+fn test_new_verified() {
+  let f = |x: f64| (5.0 * x).sin() * (-x * x).exp();
+
+  for target_error in [1e-4, 1e-8, 1e-12] {
+    let cheb = Cheb::new_verified(&f, -3.0, 3.0, target_error);
+    for i in 0..=1000 {
+      let x = -3.0 + 6.0 * (i as f64 / 1000.0);
+      assert_abs_diff_eq!(cheb.evaluate(x), f(x), epsilon = target_error);
+    }
+  }
+
+  // A cubic is resolved by the initial number of samples
+  assert_eq!(
+    Cheb::new_verified(&|x: f64| x * x * x - x, -1.0, 2.0, 1e-12).degree(),
+    3
+  );
+}
+
+#[test]
+fn test_new_with_tol() {
+  // Noise of about 1e-6 relative to the function, which the default tolerance keeps
+  let rng = std::cell::RefCell::new(Rng::with_seed(1234));
+  let f = |x: f64| x.exp() + 2e-6 * (rng.borrow_mut().f64() - 0.5);
+
+  let noisy = Cheb::new(&f, -1.0, 1.0, 64);
+  let truncated = Cheb::new_with_tol(&f, -1.0, 1.0, 64, 1e-5);
+  assert_eq!(noisy.coefficients().len(), 64);
+  assert!(truncated.coefficients().len() < 16);
+
+  // What remains still follows the underlying function, to about the noise level
+  for i in 0..=100 {
+    let x = -1.0 + 2.0 * (i as f64 / 100.0);
+    assert_abs_diff_eq!(truncated.evaluate(x), x.exp(), epsilon = 1e-4);
+  }
+
+  // The default tolerance is that of Cheb::new
+  let g = |x: f64| (3.0 * x).cos();
+  assert_eq!(
+    Cheb::new_with_tol(&g, -1.0, 1.0, 40, 1e-14).coefficients(),
+    Cheb::new(&g, -1.0, 1.0, 40).coefficients()
+  );
+}
+
+#[test]
+fn test_new_auto() {
+  let calls = Cell::new(0);
+  let f = |x: f64| {
+    calls.set(calls.get() + 1);
+    (20.0 * x).sin() + 10.0 * x.tanh() + 1.0
+  };
+
+  let cheb = Cheb::new_auto(&f, -1.0, 1.0);
+  let samples = calls.get();
+  let fixed = Cheb::new(&f, -1.0, 1.0, 40);
+
+  // At least as accurate as the fixed degree which resolves the function in the rootfinding tests
+  let error = |cheb: &Cheb| {
+    (0..=1000)
+      .map(|i| {
+        let x = -1.0 + 2.0 * (i as f64 / 1000.0);
+        (cheb.evaluate(x) - f(x)).abs()
+      })
+      .fold(0.0, f64::max)
+  };
+  assert!(error(&cheb) <= error(&fixed).max(1e-13));
+  assert_eq!(cheb.roots().len(), 1);
+
+  // Every sample of the coarser grids is reused, so the evaluations are those of a single grid
+  assert_eq!(samples, 81);
+  assert!(cheb.degree() < samples);
+
+  // A cubic is resolved by the initial number of samples
+  let cheb = Cheb::new_auto(&|x: f64| x * x * x - x, -1.0, 2.0);
+  assert_eq!(cheb.degree(), 3);
+}
+
+#[test]
+fn test_new_auto_with_tol() {
+  // Noise of about 1e-6 relative to the function, which never resolves below the default tolerance
+  let calls = Cell::new(0);
+  let rng = std::cell::RefCell::new(Rng::with_seed(1234));
+  let f = |x: f64| {
+    calls.set(calls.get() + 1);
+    x.exp() + 2e-6 * (rng.borrow_mut().f64() - 0.5)
+  };
+
+  Cheb::new_auto(&f, -1.0, 1.0);
+  assert_eq!(calls.get(), 59049);
+
+  // Resolved at the noise level by the initial number of samples
+  calls.set(0);
+  let truncated = Cheb::new_auto_with_tol(&f, -1.0, 1.0, 1e-5);
+  assert_eq!(calls.get(), 9);
+  for i in 0..=100 {
+    let x = -1.0 + 2.0 * (i as f64 / 100.0);
+    assert_abs_diff_eq!(truncated.evaluate(x), x.exp(), epsilon = 1e-4);
+  }
+
+  // The default tolerance is that of Cheb::new_auto
+  let g = |x: f64| (3.0 * x).cos();
+  assert_eq!(
+    Cheb::new_auto_with_tol(&g, -1.0, 1.0, 1e-14).coefficients(),
+    Cheb::new_auto(&g, -1.0, 1.0).coefficients()
+  );
+}
+
+#[test]
+fn test_roots_sorted_by_slope() {
+  // Roots at -1, 0.5 (double), and 3
+  let f = |x: f64| (x + 1.0) * (x - 0.5).powi(2) * (x - 3.0);
+  let roots = Cheb::new(&f, -2.0, 4.0, 10).roots_sorted_by_slope();
+
+  let slopes: Vec<f64> = roots.iter().map(|&(_, slope)| slope.abs()).collect();
+  assert!(slopes.iter().tuple_windows().all(|(a, b)| a >= b));
+
+  assert_abs_diff_eq!(roots[0].0, 3.0, epsilon = 1e-10);
+  assert_abs_diff_eq!(roots[0].1, 25.0, epsilon = 1e-8);
+  assert_abs_diff_eq!(roots[1].0, -1.0, epsilon = 1e-10);
+  assert_abs_diff_eq!(roots[1].1, -9.0, epsilon = 1e-8);
+
+  // The double root comes last, with a near-zero slope
+  for &(x, slope) in &roots[2..] {
+    assert_abs_diff_eq!(x, 0.5, epsilon = 1e-6);
+    assert_abs_diff_eq!(slope, 0.0, epsilon = 1e-5);
+  }
+}
+
+#[test]
+fn test_fit() {
+  let mut rng = Rng::with_seed(1234);
+  let f = |x: f64| (2.0 * x).sin() + 0.5 * x;
+
+  // Scattered samples, clustered towards the left
+  let xs: Vec<f64> = (0..200).map(|_| -2.0 + 5.0 * rng.f64().powi(2)).collect();
+  let ys: Vec<f64> = xs.iter().map(|&x| f(x)).collect();
+
+  let (cheb, residual) = Cheb::fit(&xs, &ys, -2.0, 3.0, 30).unwrap();
+  assert!(residual < 1e-12);
+  for i in 0..=100 {
+    let x = -2.0 + 5.0 * (i as f64 / 100.0);
+    assert_abs_diff_eq!(cheb.evaluate(x), f(x), epsilon = 1e-10);
+  }
+
+  // A line fit to noisy samples of a line has a nonzero residual
+  let noise: Vec<f64> = (0..50).map(|_| 1e-3 * (rng.f64() - 0.5)).collect();
+  let xs: Vec<f64> = (0..50).map(|i| i as f64 / 49.0).collect();
+  let ys: Vec<f64> = izip!(&xs, &noise)
+    .map(|(&x, &e)| 2.0 * x - 1.0 + e)
+    .collect();
+  let (cheb, residual) = Cheb::fit(&xs, &ys, 0.0, 1.0, 2).unwrap();
+  assert!(residual > 0.0 && residual < 1e-2);
+  assert_eq!(cheb.roots().len(), 1);
+  assert_abs_diff_eq!(cheb.roots()[0], 0.5, epsilon = 1e-3);
+}
+
+#[test]
+fn test_fit_rank_deficient() {
+  // Too few samples
+  assert!(Cheb::fit(&[0.0, 1.0], &[1.0, 2.0], 0.0, 1.0, 3).is_none());
+
+  // Enough samples, but too few distinct abscissae
+  let xs = [0.0, 0.0, 1.0, 1.0];
+  assert!(Cheb::fit(&xs, &[1.0, 1.0, 2.0, 2.0], 0.0, 1.0, 3).is_none());
+}
+
+#[test]
+fn test_total_variation() {
+  // sin rises and falls by 1 on each quarter period
+  let cheb = Cheb::new(&|x: f64| x.sin(), 0.0, 3.0 * PI, 40);
+  assert_abs_diff_eq!(cheb.total_variation(), 6.0, epsilon = 1e-8);
+
+  // A monotone function varies by the difference of its endpoint values
+  let cheb = Cheb::new(&|x: f64| x.exp(), -1.0, 2.0, 30);
+  assert_abs_diff_eq!(
+    cheb.total_variation(),
+    2f64.exp() - (-1f64).exp(),
+    epsilon = 1e-10
+  );
+}
+
+#[test]
+fn test_error_profile() {
+  let f = |x: f64| 1.0 / (1.0 + 25.0 * x * x);
+  let cheb = Cheb::new(&f, -1.0, 1.0, 20);
+  let profile = cheb.error_profile(&f, 40);
+
+  assert_eq!(profile.len(), 40);
+  assert!(profile
+    .iter()
+    .tuple_windows()
+    .all(|((x1, _), (x2, _))| x1 < x2));
+  for &(x, error) in &profile {
+    assert_eq!(error, f(x) - cheb.evaluate(x));
+  }
+
+  // The error is sampled between the nodes, so it does not vanish, even for as many points as nodes, or an odd
+  // multiple of them, whose Chebyshev points would include the nodes
+  for n in [40, 20, 60, 7, 33] {
+    let profile = cheb.error_profile(&f, n);
+    assert_eq!(profile.len(), n);
+    let max_error = profile.iter().map(|&(_, e)| e.abs()).fold(0.0, f64::max);
+    assert!(max_error > 1e-3);
+    assert!(profile.iter().all(|&(_, e)| e.abs() > 1e-6 * max_error));
+  }
+
+  // An approximation without nodes is sampled at the Chebyshev points
+  let derivative = cheb.derivative();
+  let profile = derivative.error_profile(&|x: f64| x, 5);
+  assert_abs_diff_eq!(profile[2].0, 0.0, epsilon = 1e-15);
+}
+
+#[test]
+fn test_evaluate_complex() {
+  // Roots at 1 ± 2i
+  let f = |x: f64| x * x - 2.0 * x + 5.0;
+  let cheb = Cheb::new(&f, -3.0, 4.0, 5);
+
+  let root = Complex::new(1.0, 2.0);
+  let norm = |z: Complex<f64>| z.re.hypot(z.im);
+  assert_abs_diff_eq!(norm(cheb.evaluate_complex(root)), 0.0, epsilon = 1e-12);
+  assert_abs_diff_eq!(
+    norm(cheb.evaluate_complex(root.conj())),
+    0.0,
+    epsilon = 1e-12
+  );
+
+  // On the real axis, it agrees with evaluate
+  for x in [-3.0, -0.3, 2.5, 4.0] {
+    let z = cheb.evaluate_complex(Complex::new(x, 0.0));
+    assert_abs_diff_eq!(z.re, cheb.evaluate(x), epsilon = 1e-12);
+    assert_eq!(z.im, 0.0);
+  }
+}
+
+#[test]
+fn test_crossing_roots() {
+  // Crossings at -1 and 2, and a touch point at 0.5
+  let f = |x: f64| (x + 1.0) * (x - 0.5).powi(2) * (x - 2.0);
+  let cheb = Cheb::new(&f, -2.0, 3.0, 10);
+
+  assert!(cheb.roots().iter().any(|&x| (x - 0.5).abs() < 1e-6));
+
+  let roots = cheb.crossing_roots();
+  assert_eq!(roots.len(), 2);
+  assert_abs_diff_eq!(roots[0], -1.0, epsilon = 1e-10);
+  assert_abs_diff_eq!(roots[1], 2.0, epsilon = 1e-10);
+}
+
+#[test]
+fn test_nearest_min() {
+  // Minima of cos at odd multiples of PI
+  let f = |x: f64| x.cos();
+
+  let (x, y) = nearest_min(&f, -10.0, 10.0, 40, 2.0).unwrap();
+  assert_abs_diff_eq!(x, PI, epsilon = 1e-4);
+  assert_abs_diff_eq!(y, -1.0, epsilon = 1e-8);
+
+  let (x, _) = nearest_min(&f, -10.0, 10.0, 40, -5.0).unwrap();
+  assert_abs_diff_eq!(x, -PI, epsilon = 1e-4);
+
+  assert!(nearest_min(&|x: f64| x.exp(), 0.0, 1.0, 10, 0.5).is_none());
+}
+
+#[test]
+fn test_to_horner_code() {
+  let cheb = Cheb::new(&|x: f64| x.sin() * (-x).exp(), -1.0, 4.0, 20);
+  let xs = [-1.0, -0.3, 0.0, 1.7, 3.2, 4.0];
+
+  // Compile and run the generated code, printing its values
+  let dir = std::env::temp_dir().join(format!("uniarity-codegen-{}", std::process::id()));
+  std::fs::create_dir_all(&dir).unwrap();
+  let source = format!(
+    "{}\nfn main() {{\n  for x in {xs:?} {{\n    println!(\"{{:?}}\", cheb(x));\n  }}\n}}\n",
+    cheb.to_horner_code(CodeLang::Rust)
+  );
+  std::fs::write(dir.join("cheb.rs"), source).unwrap();
+
+  let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+  let status = std::process::Command::new(rustc)
+    .current_dir(&dir)
+    .args(["--edition", "2021", "-o", "cheb", "cheb.rs"])
+    .status()
+    .unwrap();
+  assert!(status.success());
+
+  let output = std::process::Command::new(dir.join("cheb"))
+    .output()
+    .unwrap();
+  let values: Vec<String> = String::from_utf8(output.stdout)
+    .unwrap()
+    .lines()
+    .map(str::to_string)
+    .collect();
+  let expected: Vec<String> = xs
+    .iter()
+    .map(|&x| format!("{:?}", cheb.evaluate(x)))
+    .collect();
+  assert_eq!(values, expected);
+
+  std::fs::remove_dir_all(&dir).unwrap();
+
+  let c = cheb.to_horner_code(CodeLang::C);
+  assert!(c.starts_with("/* A Chebyshev approximation on [-1.0, 4.0]. */\ndouble cheb(double x) {"));
+}
+
+#[test]
+fn test_nondegenerate_extrema() {
+  // Minima at -1 and 1, maxima between them and between 1 and 3, and a flat inflection at 3
+  let f = |x: f64| -(x + 1.0).powi(2) * (x - 1.0).powi(2) * (x - 3.0).powi(3) * 1e-3;
+  let cheb = Cheb::new(&f, -2.0, 4.0, 12);
+
+  let extrema = cheb.nondegenerate_extrema();
+  let kinds: Vec<_> = extrema.iter().map(|&(_, _, kind)| kind).collect();
+  assert_eq!(
+    kinds,
+    [
+      Extremum::Minimum,
+      Extremum::Maximum,
+      Extremum::Minimum,
+      Extremum::Maximum
+    ]
+  );
+  assert_abs_diff_eq!(extrema[0].0, -1.0, epsilon = 1e-8);
+  assert_abs_diff_eq!(extrema[2].0, 1.0, epsilon = 1e-8);
+
+  for &(x, y, kind) in &extrema {
+    assert_abs_diff_eq!(y, cheb.evaluate(x));
+    let curvature = cheb.derivative().derivative().evaluate(x);
+    match kind {
+      Extremum::Minimum => assert!(curvature > 0.0),
+      Extremum::Maximum => assert!(curvature < 0.0),
+    }
+  }
+}
+
+#[test]
+fn test_level_crossings() {
+  let cheb = Cheb::new(&|x: f64| x.sin(), 0.0, 3.0 * PI, 20);
+
+  let crossings = cheb.level_crossings(0.5);
+  let expected = [
+    (PI / 6.0, CrossDirection::Rising),
+    (5.0 * PI / 6.0, CrossDirection::Falling),
+    (13.0 * PI / 6.0, CrossDirection::Rising),
+    (17.0 * PI / 6.0, CrossDirection::Falling),
+  ];
+
+  assert_eq!(crossings.len(), expected.len());
+  for (&(x, direction), &(expected_x, expected_direction)) in crossings.iter().zip(&expected) {
+    assert_abs_diff_eq!(x, expected_x, epsilon = 1e-6);
+    assert_eq!(direction, expected_direction);
+  }
+
+  assert!(cheb.level_crossings(2.0).is_empty());
+}
+
+#[test]
+fn test_new_compensated() {
+  // A quadratic, whose exact coefficients are 3, 2, and 1. At this degree, the rounding noise in the coefficients
+  // computed by Cheb::new exceeds the truncation threshold, leaving hundreds of spurious coefficients
+  let f = |x: f64| 3.0 + 2.0 * x + (2.0 * x * x - 1.0);
+  let cheb = Cheb::new_compensated(&f, -1.0, 1.0, 2048);
+  assert_eq!(cheb.degree(), 2);
+
+  for i in 0..=100 {
+    let x = -1.0 + 0.02 * i as f64;
+    assert_abs_diff_eq!(cheb.evaluate(x), f(x), epsilon = 1e-14);
+  }
+
+  // Agrees with Cheb::new at modest degree
+  let f = |x: f64| (3.0 * x).sin() + x.exp();
+  let (a, b) = (
+    Cheb::new(&f, -2.0, 1.0, 30),
+    Cheb::new_compensated(&f, -2.0, 1.0, 30),
+  );
+  assert_eq!(a.degree(), b.degree());
+  for i in 0..=100 {
+    let x = -2.0 + 0.03 * i as f64;
+    assert_abs_diff_eq!(a.evaluate(x), b.evaluate(x), epsilon = 1e-14);
+  }
+}
+
+#[test]
+fn test_roots_with_solver() {
+  // A solver which delegates to the default, counting its uses
+  struct Counting(Cell<usize>);
+
+  impl EigenSolver for Counting {
+    fn eigenvalues(&self, matrix: MatRef<'_, f64>) -> Vec<Complex<f64>> {
+      self.0.set(self.0.get() + 1);
+      FaerEigenSolver.eigenvalues(matrix)
+    }
+  }
+
+  let cheb = Cheb::new(&|x: f64| (3.0 * x).cos(), -2.0, 2.0, 20);
+  let solver = Counting(Cell::new(0));
+
+  assert_eq!(cheb.roots_with_solver(&solver), cheb.roots());
+  assert_eq!(solver.0.get(), 1);
+}
+
+#[test]
+fn test_roots_balanced() {
+  // A solver which computes the eigenvalues of the colleague matrix as is, without balancing it
+  struct Unbalanced;
+
+  impl EigenSolver for Unbalanced {
+    fn eigenvalues(&self, matrix: MatRef<'_, f64>) -> Vec<Complex<f64>> {
+      matrix.eigenvalues().unwrap()
+    }
+  }
+
+  // A decaying oscillation, whose coefficients fall over many orders of magnitude, so that the last row of the
+  // colleague matrix, divided by the tiny leading coefficient, dwarfs the rest
+  let f = |x: f64| (10.0 * x).sin() * (-2.5 * (x + 1.0)).exp();
+  let cheb = Cheb::new(&f, -1.0, 1.0, 400);
+  let exact: Vec<f64> = (-3..=3).map(|k| k as f64 * PI / 10.0).collect();
+
+  let error = |roots: Vec<f64>| {
+    assert_eq!(roots.len(), exact.len());
+    izip!(roots, &exact)
+      .map(|(x, y)| (x - y).abs())
+      .fold(0.0, f64::max)
+  };
+  assert!(error(cheb.roots()) < 1e-13);
+  assert!(error(cheb.roots_with_solver(&Unbalanced)) > 1e-8);
+}
+
+#[test]
+fn test_is_monotonic() {
+  assert_eq!(
+    is_monotonic(&|x: f64| x.exp(), -1.0, 2.0, 20),
+    Some(Ordering::Greater)
+  );
+  assert_eq!(
+    is_monotonic(&|x: f64| -x.atan(), -5.0, 5.0, 40),
+    Some(Ordering::Less)
+  );
+  assert_eq!(is_monotonic(&|_| 2.0, 0.0, 1.0, 8), Some(Ordering::Equal));
+  assert_eq!(is_monotonic(&|x: f64| x.sin(), 0.0, 4.0, 20), None);
+
+  // A derivative which only touches zero, or has its root at an end of the interval
+  assert_eq!(
+    is_monotonic(&|x: f64| x.powi(3), -1.0, 1.0, 8),
+    Some(Ordering::Greater)
+  );
+  assert_eq!(
+    is_monotonic(&|x: f64| x * x, 0.0, 1.0, 8),
+    Some(Ordering::Greater)
+  );
+  assert_eq!(is_monotonic(&|x: f64| x * x, -1.0, 1.0, 8), None);
+}
+
+#[test]
+fn test_arc_length() {
+  // A straight line, whose length is the distance between its ends
+  let cheb = Cheb::new(&|x: f64| 0.75 * x + 2.0, -1.0, 3.0, 4);
+  assert_abs_diff_eq!(cheb.arc_length(), 5.0, epsilon = 1e-12);
+
+  // The upper half of a circle of radius 1, away from its vertical tangents, subtends an angle of 2 * asin(0.8)
+  let cheb = Cheb::new(&|x: f64| (1.0 - x * x).sqrt(), -0.8, 0.8, 60);
+  assert_abs_diff_eq!(cheb.arc_length(), 2.0 * 0.8f64.asin(), epsilon = 1e-10);
+
+  // The catenary cosh(x), whose arc length from 0 to x is sinh(x)
+  let cheb = Cheb::new(&|x: f64| x.cosh(), 0.0, 2.0, 30);
+  assert_abs_diff_eq!(cheb.arc_length(), 2f64.sinh(), epsilon = 1e-10);
+}
+
+#[test]
+fn test_verify_roots() {
+  let cheb = Cheb::new(&|x: f64| x.sin() + 0.2, -4.0, 4.0, 16);
+
+  let roots = cheb.roots();
+  let report = cheb.verify_roots(&roots);
+  assert!(report.is_verified());
+  assert_eq!(report.sign_changes, roots.len());
+  for residual in report.residuals {
+    assert!(residual.abs() < 1e-8);
+  }
+
+  // A spurious root stands out
+  let report = cheb.verify_roots(&[roots[0], roots[1], roots[2], 0.5]);
+  assert_eq!(report.spurious.len(), 1);
+  assert_eq!(report.spurious[0].0, 0.5);
+  assert!(report.spurious[0].1.abs() > 0.1);
+  assert!(report.residuals[0].abs() < report.tol);
+  assert_eq!(report.missed(), 0);
+  assert!(!report.is_verified());
+
+  // A missed root shows up as a shortfall against the sign changes, even in place of a spurious one
+  let report = cheb.verify_roots(&roots[1..]);
+  assert!(report.spurious.is_empty());
+  assert_eq!(report.missed(), 1);
+  assert_eq!(cheb.verify_roots(&[roots[1], 0.5]).missed(), 2);
+  assert!(!report.is_verified());
+}
+
+#[test]
+fn test_new_adaptive() {
+  let f = |x: f64| (20.0 * x).sin();
+  let cheb = Cheb::new_adaptive(&f, -10.0, 10.0);
+
+  assert!(cheb.pieces().len() > 1);
+  assert!(cheb.pieces().iter().all(|piece| piece.degree() <= 128));
+
+  // The roots are k * pi / 20, including the split point at zero
+  let roots = cheb.roots();
+  assert_eq!(roots.len(), 127);
+  for (k, &root) in (-63..=63).zip(&roots) {
+    assert_abs_diff_eq!(root, k as f64 * PI / 20.0, epsilon = 1e-12);
+  }
+
+  for x in [-9.99, -3.3, 0.0, 0.123, 7.5] {
+    assert_abs_diff_eq!(cheb.evaluate(x), f(x), epsilon = 1e-12);
+  }
+
+  // A function resolved by a single approximation is not split
+  let cheb = Cheb::new_adaptive(&|x: f64| x * x - 2.0, 0.0, 3.0);
+  assert_eq!(cheb.pieces().len(), 1);
+  assert_abs_diff_eq!(cheb.roots()[0], 2.0_f64.sqrt(), epsilon = 1e-12);
+}
+
+#[test]
+fn test_derivative() {
+  let f = |x: f64| x.exp() * (2.0 * x).cos() + x * x;
+  let (a, b) = (-1.5, 2.5);
+  let cheb = Cheb::new(&f, a, b, 80);
+  let derivative = cheb.derivative();
+
+  for i in 0..=20 {
+    let x = a + (b - a) * i as f64 / 20.0;
+    let expected = numerical_derivative(&|x| cheb.evaluate(x), x, 1e-5);
+    assert_relative_eq!(
+      derivative.evaluate(x),
+      expected,
+      epsilon = 1e-8,
+      max_relative = 1e-8
+    );
+  }
+
+  // The roots of the derivative are the critical points of the original
+  let g = |x: f64| (x - 1.0).powi(2) * (x + 1.0);
+  let critical = Cheb::new(&g, -2.0, 2.0, 8).derivative().roots();
+  assert_eq!(critical.len(), 2);
+  assert_abs_diff_eq!(critical[0], -1.0 / 3.0, epsilon = 1e-12);
+  assert_abs_diff_eq!(critical[1], 1.0, epsilon = 1e-12);
+
+  // Constants and empty approximations have an empty derivative
+  assert_eq!(Cheb::new(&|_| 3.0, 0.0, 1.0, 4).derivative().degree(), 0);
+}
+
+#[test]
+fn test_quadratic_roots() {
+  let mut rng = Rng::with_seed(1234);
+
+  for _ in 0..N_TESTS {
+    let (r0, r1) = (rng.f64() * 2.0 - 1.0, rng.f64() * 2.0 - 1.0);
+    let (p, q, r) = (1.0, -(r0 + r1), r0 * r1);
+    let cheb = Cheb::new(&|x: f64| (p * x + q) * x + r, -1.0, 1.0, 3);
+    assert_eq!(cheb.degree(), 2);
+
+    // The colleague matrix of c0 + c1 T1 + c2 T2, with c2 = p / 2, c1 = q, and c0 = r + p / 2
+    let (c0, c1, c2) = (r + 0.5 * p, q, 0.5 * p);
+    let colleague = Mat::from_fn(2, 2, |i, j| match (i, j) {
+      (0, 0) => 0.0,
+      (0, 1) => 1.0,
+      (1, 0) => 0.5 - c0 / (2.0 * c2),
+      _ => -c1 / (2.0 * c2),
+    });
+    let mut expected: Vec<f64> = FaerEigenSolver
+      .eigenvalues(colleague.as_ref())
+      .iter()
+      .map(|z| z.re)
+      .collect();
+    expected.sort_by_key(|&v| OrderedFloat(v));
+
+    let roots = cheb.roots();
+    assert_eq!(roots.len(), 2);
+    for (&root, &expected) in roots.iter().zip(&expected) {
+      assert_abs_diff_eq!(root, expected, epsilon = 1e-10);
+    }
+  }
+
+  // A small root beside a large one does not suffer cancellation
+  let cheb = Cheb::new(&|x: f64| (x - 1e-9) * (x - 0.9), -1.0, 1.0, 3);
+  let roots = cheb.roots();
+  assert_eq!(roots.len(), 2);
+  assert_relative_eq!(roots[0], 1e-9, max_relative = 1e-6);
+  assert_relative_eq!(roots[1], 0.9, max_relative = 1e-14);
+
+  // Complex and out-of-interval roots are excluded
+  assert!(Cheb::new(&|x: f64| x * x + 1.0, -1.0, 1.0, 3)
+    .roots()
+    .is_empty());
+  assert_eq!(
+    Cheb::new(&|x: f64| (x - 0.5) * (x - 3.0), -1.0, 1.0, 3)
+      .roots()
+      .len(),
+    1
+  );
+}
+
+#[test]
+fn test_complex_roots() {
+  // (x - 1)((x - 2)^2 + 1), with roots at 1 and 2 +- i
+  let cheb = Cheb::new(
+    &|x: f64| (x - 1.0) * ((x - 2.0).powi(2) + 1.0),
+    0.0,
+    4.0,
+    10,
+  );
+  let roots = cheb.complex_roots();
+  let expected = [
+    Complex::new(1.0, 0.0),
+    Complex::new(2.0, -1.0),
+    Complex::new(2.0, 1.0),
+  ];
+
+  assert_eq!(roots.len(), 3);
+  for (root, expected) in roots.iter().zip(expected) {
+    assert_abs_diff_eq!((root - expected).norm(), 0.0, epsilon = 1e-12);
+  }
+
+  // Only the real root is reported by roots
+  assert_eq!(cheb.roots().len(), 1);
+  assert_abs_diff_eq!(cheb.roots()[0], 1.0, epsilon = 1e-12);
+
+  // The degenerate quadratic and linear cases are solved directly
+  let roots = Cheb::new(&|x: f64| x * x + 1.0, -1.0, 1.0, 10).complex_roots();
+  assert_abs_diff_eq!(
+    (roots[0] - Complex::new(0.0, -1.0)).norm(),
+    0.0,
+    epsilon = 1e-14
+  );
+  assert_abs_diff_eq!(
+    (roots[1] - Complex::new(0.0, 1.0)).norm(),
+    0.0,
+    epsilon = 1e-14
+  );
+  let roots = Cheb::new(&|x: f64| x - 3.0, 0.0, 1.0, 10).complex_roots();
+  assert_abs_diff_eq!(
+    (roots[0] - Complex::new(3.0, 0.0)).norm(),
+    0.0,
+    epsilon = 1e-12
+  );
+}
+
+#[test]
+fn test_integrate() {
+  // The odd terms cancel over the symmetric interval, leaving 0.6 * 4
+  let cheb = Cheb::new(&|x: f64| x * x * x - x + 0.6, -2.0, 2.0, 6);
+  assert_abs_diff_eq!(cheb.integrate(), 2.4, epsilon = 1e-13);
+
+  let cheb = Cheb::new(&|x: f64| x.exp(), 0.5, 3.0, 30);
+  assert_relative_eq!(
+    cheb.integrate(),
+    3.0_f64.exp() - 0.5_f64.exp(),
+    max_relative = 1e-14
+  );
+
+  assert_eq!(Cheb::new(&|x: f64| x, 0.0, 1.0, 0).integrate(), 0.0);
+}
+
+#[test]
+fn test_antiderivative() {
+  let (a, b) = (-1.0, 2.5);
+  let cheb = Cheb::new(&|x: f64| x.cos() + x * x, a, b, 30);
+  let antiderivative = cheb.antiderivative();
+
+  for i in 0..=20 {
+    let x = a + (b - a) * i as f64 / 20.0;
+    let expected = x.sin() - a.sin() + (x.powi(3) - a.powi(3)) / 3.0;
+    assert_abs_diff_eq!(antiderivative.evaluate(x), expected, epsilon = 1e-13);
+  }
+  assert_abs_diff_eq!(
+    antiderivative.evaluate(b),
+    cheb.integrate(),
+    epsilon = 1e-13
+  );
+
+  // Differentiating recovers the original
+  let derivative = antiderivative.derivative();
+  for x in [-0.9, 0.0, 1.3, 2.4] {
+    assert_abs_diff_eq!(derivative.evaluate(x), cheb.evaluate(x), epsilon = 1e-12);
+  }
+}
+
+#[test]
+fn test_inverse() {
+  let cheb = Cheb::new(&|x: f64| x.exp() + x, 0.0, 2.0, 30);
+  let inverse = cheb.inverse().unwrap();
+
+  for i in 0..=20 {
+    let x = 2.0 * i as f64 / 20.0;
+    assert_abs_diff_eq!(inverse.evaluate(cheb.evaluate(x)), x, epsilon = 1e-10);
+  }
+
+  // Decreasing functions invert onto [f(b), f(a)]
+  let cheb = Cheb::new(&|x: f64| 1.0 / (1.0 + x), 0.0, 3.0, 40);
+  let inverse = cheb.inverse().unwrap();
+  assert_abs_diff_eq!(inverse.evaluate(0.5), 1.0, epsilon = 1e-10);
+  assert_abs_diff_eq!(inverse.evaluate(0.3), 7.0 / 3.0, epsilon = 1e-10);
+
+  // Neither non-monotonic nor constant functions are invertible
+  assert!(Cheb::new(&|x: f64| x.sin(), 0.0, 3.0, 20)
+    .inverse()
+    .is_none());
+  assert!(Cheb::new(&|_| 2.0, 0.0, 3.0, 4).inverse().is_none());
+}
+
+#[test]
+fn test_fast_coefficients() {
+  // The compensated path computes each coefficient directly, as a reference for the fast transform
+  let f = |x: f64| (3.0 * x).sin() / (1.0 + x * x) + 0.1 * x;
+  for n in [7, 30, 64, 97, 300] {
+    let fast = Cheb::new(&f, -2.0, 3.0, n);
+    let direct = Cheb::new_compensated(&f, -2.0, 3.0, n);
+
+    assert_eq!(fast.degree(), direct.degree());
+    for i in 0..=50 {
+      let x = -2.0 + 5.0 * i as f64 / 50.0;
+      assert_abs_diff_eq!(fast.evaluate(x), direct.evaluate(x), epsilon = 1e-12);
+    }
+  }
+}
+
+#[test]
+fn test_from_coefficients() {
+  let cheb = Cheb::new(&|x: f64| x.exp() * x.sin(), -1.0, 2.0, 30);
+  let (a, b) = cheb.interval();
+  let copy = Cheb::from_coefficients(a, b, cheb.coefficients().to_vec());
+
+  assert_eq!(copy.coefficients(), cheb.coefficients());
+  for i in 0..=20 {
+    let x = -1.0 + 3.0 * i as f64 / 20.0;
+    assert_eq!(copy.evaluate(x), cheb.evaluate(x));
+  }
+
+  // 1 + 2 T_1 + 0.5 T_2, with trailing zeros removed
+  let cheb = Cheb::from_coefficients(0.0, 2.0, vec![1.0, 2.0, 0.5, 0.0, 0.0]);
+  assert_eq!(cheb.degree(), 2);
+  assert_abs_diff_eq!(cheb.evaluate(2.0), 3.5, epsilon = 1e-15);
+  assert_abs_diff_eq!(cheb.evaluate(1.0), 0.5, epsilon = 1e-15);
+}
+
+#[test]
+#[should_panic]
+fn test_from_coefficients_bad_interval() {
+  Cheb::from_coefficients(1.0, 0.0, vec![1.0]);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serialized_round_trip() {
+  let cheb = Cheb::new(&|x: f64| x.exp() * x.sin(), -1.0, 2.0, 30);
+  let bytes = bincode::serialize(&cheb).unwrap();
+  let copy: Cheb = bincode::deserialize(&bytes).unwrap();
+
+  assert_eq!(copy.interval(), cheb.interval());
+  assert_eq!(copy.coefficients(), cheb.coefficients());
+  for i in 0..=20 {
+    let x = -1.0 + 3.0 * i as f64 / 20.0;
+    assert_eq!(copy.evaluate(x), cheb.evaluate(x));
+  }
+
+  // An interval out of order is rejected
+  let bytes = bincode::serialize(&(1.0, 0.0, vec![1.0])).unwrap();
+  assert!(bincode::deserialize::<Cheb>(&bytes).is_err());
+}
+
+#[test]
+fn test_evaluate_slice() {
+  let cheb = Cheb::new(&|x: f64| x.cos() * x.exp(), -1.0, 3.0, 25);
+
+  // A length which is not a multiple of the chunk size
+  let xs = (0..=100)
+    .map(|i| -1.0 + 4.0 * i as f64 / 100.0)
+    .collect_vec();
+  let values = cheb.evaluate_vec(&xs);
+  for (&x, &value) in xs.iter().zip(&values) {
+    assert_eq!(value, cheb.evaluate(x));
+  }
+
+  let empty = Cheb::new(&|x: f64| x, 0.0, 1.0, 0);
+  assert_eq!(empty.evaluate_vec(&[0.5, 1.0]), [0.0, 0.0]);
+  assert!(cheb.evaluate_vec(&[]).is_empty());
+}
+
+#[test]
+#[should_panic]
+fn test_evaluate_slice_length_mismatch() {
+  let cheb = Cheb::new(&|x: f64| x, 0.0, 1.0, 4);
+  cheb.evaluate_slice(&[0.5, 1.0], &mut [0.0]);
+}
+
+#[test]
+fn test_extrema() {
+  let f = |x: f64| x * x.exp() + x * x;
+  let (a, b) = (-3.0, 1.0);
+  let cheb = Cheb::new(&f, a, b, 30);
+
+  let extrema = cheb.extrema();
+  assert_eq!(extrema.first(), Some(&a));
+  assert_eq!(extrema.last(), Some(&b));
+
+  // Compare against a brute-force scan
+  let scan = (0..=100_000)
+    .map(|i| a + (b - a) * i as f64 / 100_000.0)
+    .map(|x| (x, f(x)))
+    .collect_vec();
+  let scan_min = scan
+    .iter()
+    .copied()
+    .min_by_key(|&(_, y)| OrderedFloat(y))
+    .unwrap();
+  let scan_max = scan
+    .iter()
+    .copied()
+    .max_by_key(|&(_, y)| OrderedFloat(y))
+    .unwrap();
+
+  let (x, y) = cheb.min_on_interval();
+  assert_abs_diff_eq!(x, scan_min.0, epsilon = 1e-4);
+  assert!(y <= scan_min.1 + 1e-12);
+
+  let (x, y) = cheb.max_on_interval();
+  assert_eq!(x, scan_max.0);
+  assert_abs_diff_eq!(y, scan_max.1, epsilon = 1e-12);
+}
+
+#[test]
+fn test_new_log() {
+  // Spanning about 40 orders of magnitude
+  let f = |x: f64| (-x * x).exp() * (2.0 + x.sin());
+  let (a, b) = (0.0, 9.5);
+
+  let log_cheb = Cheb::new_log(&f, a, b, 80);
+  let cheb = Cheb::new(&f, a, b, 80);
+
+  for i in 0..=50 {
+    let x = a + (b - a) * i as f64 / 50.0;
+    assert_relative_eq!(log_cheb.evaluate(x), f(x), max_relative = 1e-10);
+  }
+
+  // Direct fitting loses the relative accuracy of the small values
+  let x = 9.0;
+  assert!(((cheb.evaluate(x) - f(x)) / f(x)).abs() > 1.0);
+
+  assert_eq!(
+    log_cheb.log().degree(),
+    log_cheb.log().coefficients().len() - 1
+  );
+}
+
+#[test]
+#[should_panic]
+fn test_new_log_nonpositive() {
+  Cheb::new_log(&|x: f64| x, -1.0, 1.0, 8);
+}
+
+#[test]
+fn test_centroid() {
+  // A uniform density is centered on its interval
+  let cheb = Cheb::new(&|_| 0.25, 1.0, 5.0, 4);
+  assert_abs_diff_eq!(cheb.centroid(), 3.0, epsilon = 1e-14);
+
+  // The density 2x on [0, 1] has mean 2/3
+  let cheb = Cheb::new(&|x: f64| 2.0 * x, 0.0, 1.0, 4);
+  assert_abs_diff_eq!(cheb.centroid(), 2.0 / 3.0, epsilon = 1e-14);
+
+  // An unnormalized Gaussian, well within its interval, is centered on its mean
+  let cheb = Cheb::new(
+    &|x: f64| (-0.5 * ((x - 1.5) / 0.4).powi(2)).exp(),
+    -2.0,
+    6.0,
+    80,
+  );
+  assert_abs_diff_eq!(cheb.centroid(), 1.5, epsilon = 1e-12);
+
+  // The integral of an odd function vanishes
+  assert!(!Cheb::new(&|x: f64| x, -1.0, 1.0, 4).centroid().is_finite());
+}
@@ -0,0 +1,11 @@
+use uniarity::consts::{GOLDEN_RATIO, GOLDEN_RATIO_CONJUGATE, GOLDEN_SECTION};
+
+#[test]
+fn test_golden_ratio() {
+  // The defining identities hold to within rounding
+  assert_eq!(GOLDEN_RATIO, 0.5 * (1.0 + 5f64.sqrt()));
+  assert_eq!(GOLDEN_RATIO_CONJUGATE, GOLDEN_RATIO - 1.0);
+  assert!((GOLDEN_RATIO * GOLDEN_RATIO_CONJUGATE - 1.0).abs() <= f64::EPSILON);
+  assert!((GOLDEN_SECTION - GOLDEN_RATIO_CONJUGATE.powi(2)).abs() <= f64::EPSILON);
+  assert!((GOLDEN_SECTION - (2.0 - GOLDEN_RATIO)).abs() <= f64::EPSILON);
+}
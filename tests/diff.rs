@@ -0,0 +1,37 @@
+use approx::assert_abs_diff_eq;
+
+use uniarity::diff::{numerical_derivative, numerical_second_derivative};
+use uniarity::initial::newtons_method;
+
+#[test]
+fn test_derivatives() {
+  let f = |x: f64| x.exp() * x.sin();
+  let fp = |x: f64| x.exp() * (x.sin() + x.cos());
+  let fpp = |x: f64| 2.0 * x.exp() * x.cos();
+
+  for x in [-2.0_f64, -0.5, 0.0, 1.0, 3.0] {
+    let scale = f64::max(x.abs(), 1.0);
+    let h1 = f64::EPSILON.cbrt() * scale;
+    let h2 = f64::EPSILON.powf(0.25) * scale;
+
+    assert_abs_diff_eq!(
+      numerical_derivative(&f, x, h1),
+      fp(x),
+      epsilon = 1e-8 * scale.exp()
+    );
+    assert_abs_diff_eq!(
+      numerical_second_derivative(&f, x, h2),
+      fpp(x),
+      epsilon = 1e-6 * scale.exp()
+    );
+  }
+}
+
+#[test]
+fn test_newton_with_numerical_derivative() {
+  let f = |x: f64| x.cos() - x;
+  let g = |x: f64| numerical_derivative(&f, x, 1e-6);
+
+  let x = newtons_method(&f, &g, 1.0, 1e-15);
+  assert_abs_diff_eq!(f(x), 0.0, epsilon = 1e-15);
+}
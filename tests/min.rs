@@ -1,7 +1,13 @@
 use approx::assert_abs_diff_eq;
+use std::cell::{Cell, RefCell};
 use std::f64::consts::PI;
 
-use uniarity::min::min;
+use uniarity::generic;
+use uniarity::min::{
+  golden_section, max, max_by_inspection, min, min_by_inspection, min_by_inspection_weighted,
+  min_from_bracket, min_over_grid_then_refine, min_verified, min_with, min_with_curvature,
+  min_with_init, IncrementalMinimizer, MinQuality,
+};
 
 #[test]
 fn test_minimization_degenerate() {
@@ -23,3 +29,237 @@ fn test_minimization() {
   assert_abs_diff_eq!(x, -0.35173371124919584, epsilon = 1e-9);
   assert_abs_diff_eq!(y, 0.8271840261275243, epsilon = 1e-9);
 }
+
+#[test]
+fn test_generic_minimization() {
+  let (x, y) = generic::min(&|x: f64| x.exp() + x * x, -2.0, 2.0, 1e-15);
+  assert_eq!((x, y), min(&|x| x.exp() + x * x, -2.0, 2.0, 1e-15));
+
+  let (x, y) = generic::min(&|x: f32| x.exp() + x * x, -2.0, 2.0, 1e-7);
+  assert_abs_diff_eq!(x, -0.351_733_7, epsilon = 1e-3);
+  assert_abs_diff_eq!(y, 0.827_184, epsilon = 1e-6);
+}
+
+#[test]
+fn test_minimization_value() {
+  let evaluated = RefCell::new(Vec::new());
+  let f = |x: f64| {
+    evaluated.borrow_mut().push(x);
+    (x - 0.3).powi(2) + x.sin()
+  };
+
+  let (x, y) = min(&f, -2.0, 2.0, 1e-12);
+
+  // The minimum's value is returned without evaluating it again
+  assert_eq!(evaluated.borrow().iter().filter(|&&e| e == x).count(), 1);
+  assert_eq!(y, f(x));
+}
+
+#[test]
+fn test_minimization_over_grid() {
+  // A log-spaced grid, clustered near zero
+  let grid: Vec<f64> = (0..=40)
+    .map(|i| 10f64.powf(-3.0 + 0.1 * i as f64))
+    .collect();
+
+  let (x, y) = min_over_grid_then_refine(&|x: f64| (x.ln() - 1.0).powi(2), &grid, 1e-12);
+  assert_abs_diff_eq!(x, 1f64.exp(), epsilon = 1e-6);
+  assert_abs_diff_eq!(y, 0.0, epsilon = 1e-12);
+
+  // The minimum lies beyond the end of the grid
+  let (x, y) = min_over_grid_then_refine(&|x: f64| -x, &grid, 1e-12);
+  assert_eq!((x, y), (grid[40], -grid[40]));
+}
+
+#[test]
+fn test_incremental_minimization() {
+  let mut minimizer = IncrementalMinimizer::new(-5.0, 5.0, 8);
+
+  // A minimum drifting slowly to the right
+  for frame in 0..100 {
+    let center = -2.0 + 0.04 * frame as f64;
+    let f = |x: f64| (x - center).powi(2) + 1.0;
+
+    let (x, y) = minimizer.update(&f, 1e-10);
+    assert_eq!(minimizer.minimum(), (x, y));
+    if frame >= 5 {
+      assert_abs_diff_eq!(x, center, epsilon = 1e-6);
+    }
+  }
+
+  // A drastic change, where the minimum jumps far away
+  let f = |x: f64| (x + 4.0).powi(2);
+  let (x, _) = (0..5).map(|_| minimizer.update(&f, 1e-10)).last().unwrap();
+  assert_abs_diff_eq!(x, -4.0, epsilon = 1e-6);
+}
+
+#[test]
+fn test_minimization_verified() {
+  let (x, y, quality) = min_verified(&|x| x.exp() + x * x, -2.0, 2.0, 1e-15);
+  assert_abs_diff_eq!(x, -0.35173371124919584, epsilon = 1e-9);
+  assert_abs_diff_eq!(y, 0.8271840261275243, epsilon = 1e-9);
+  assert!(quality.is_minimum);
+
+  // A minimum at the end of the bracket
+  let (_, _, quality) = min_verified(&|x| 1.0 - PI * x, 0.0, 1.0, 1e-15);
+  assert!(quality.is_minimum);
+
+  // Flat functions have no distinct minimum
+  let (_, _, quality) = min_verified(&|_| 0.0, 0.0, 1.0, 1e-15);
+  assert_eq!(quality, MinQuality { is_minimum: false });
+
+  let (x, _, quality) = min_verified(&|x: f64| (x - 0.5).abs().max(0.2), 0.0, 1.0, 1e-12);
+  assert!((0.3..=0.7).contains(&x));
+  assert!(!quality.is_minimum);
+}
+
+#[test]
+fn test_minimization_by_weighted_inspection() {
+  let f = |x: f64| (x - 0.7231).powi(2);
+
+  // A constant weight samples uniformly
+  let uniform = min_by_inspection(&f, 0.0, 2.0, 11);
+  let (x, y) = min_by_inspection_weighted(&f, 0.0, 2.0, 11, &|_| 3.0);
+  assert_abs_diff_eq!(x, uniform.0, epsilon = 1e-12);
+  assert_abs_diff_eq!(y, uniform.1, epsilon = 1e-12);
+
+  // Concentrating the samples where the minimum is expected finds it more precisely
+  let weight = |x: f64| (-((x - 0.7) / 0.05).powi(2)).exp();
+  let (x, _) = min_by_inspection_weighted(&f, 0.0, 2.0, 11, &weight);
+  assert!((x - 0.7231).abs() < 0.01);
+  assert!((x - 0.7231).abs() < (uniform.0 - 0.7231).abs());
+}
+
+#[test]
+fn test_minimization_with_init() {
+  // Rises steeply to the left of the minimum near 0.1, and gently to the right
+  let evaluations = RefCell::new(0);
+  let f = |x: f64| {
+    *evaluations.borrow_mut() += 1;
+    (-20.0 * (x - 0.1)).exp() + 20.0 * (x - 0.1)
+  };
+
+  let (x, y) = min(&f, 0.0, 1.0, 1e-12);
+  let from_midpoint = evaluations.replace(0);
+  let (x_init, y_init) = min_with_init(&f, 0.0, 1.0, 0.1, 1e-12);
+  let from_init = evaluations.replace(0);
+
+  assert_abs_diff_eq!(x, 0.1, epsilon = 1e-6);
+  assert_abs_diff_eq!(x_init, 0.1, epsilon = 1e-6);
+  assert_abs_diff_eq!(y, y_init, epsilon = 1e-12);
+  assert!(from_init < from_midpoint);
+
+  assert_eq!(min_with_init(&f, 0.0, 1.0, 0.5, 1e-12), (x, y));
+}
+
+#[test]
+fn test_minimization_with_curvature() {
+  // cosh(x) has its minimum at 0, with f''(0) = 1
+  let (x, fx, curvature) = min_with_curvature(&|x: f64| x.cosh(), -1.0, 2.0, 1e-10);
+  assert_abs_diff_eq!(x, 0.0, epsilon = 1e-8);
+  assert_abs_diff_eq!(fx, 1.0, epsilon = 1e-15);
+  assert_abs_diff_eq!(curvature, 1.0, epsilon = 1e-6);
+
+  // Away from unit scale, with f''(x) = 6e4 at the minimum
+  let f = |x: f64| 3e4 * (x - 150.0).powi(2) + 7.0;
+  let (x, _, curvature) = min_with_curvature(&f, 100.0, 200.0, 1e-12);
+  assert_abs_diff_eq!(x, 150.0, epsilon = 1e-6);
+  assert_abs_diff_eq!(curvature, 6e4, epsilon = 1e-3);
+
+  // A flat minimum has a small curvature
+  let (_, _, curvature) = min_with_curvature(&|x: f64| (x - 0.3).powi(4), -1.0, 1.0, 1e-10);
+  assert!(curvature.abs() < 1e-4);
+
+  // A minimum at the end of the bracket, where Brent's algorithm stops just inside it, has no reliable estimate
+  let (x, _, curvature) = min_with_curvature(&|x: f64| (x - 2.0) * (x - 2.0), 0.0, 1.0, 1e-10);
+  assert_abs_diff_eq!(x, 1.0, epsilon = 1e-8);
+  assert!(curvature.is_nan());
+  let (x, _, curvature) = min_with_curvature(&|x: f64| x.exp(), -1.0, 1.0, 1e-10);
+  assert_abs_diff_eq!(x, -1.0, epsilon = 1e-8);
+  assert!(curvature.is_nan());
+}
+
+#[test]
+fn test_golden_section() {
+  let f = |x: f64| x.exp() + x * x;
+  let (x, y) = golden_section(&f, -2.0, 2.0, 1e-15);
+  let (x_brent, y_brent) = min(&f, -2.0, 2.0, 1e-15);
+  assert_abs_diff_eq!(x, x_brent, epsilon = 1e-6);
+  assert_abs_diff_eq!(y, y_brent, epsilon = 1e-12);
+
+  // Each iteration evaluates one new point
+  let evaluations = Cell::new(0);
+  let g = |x: f64| {
+    evaluations.set(evaluations.get() + 1);
+    (x - 0.3).abs()
+  };
+  let (x, _) = golden_section(&g, 0.0, 1.0, 1e-10);
+  assert_abs_diff_eq!(x, 0.3, epsilon = 1e-9);
+  assert!(evaluations.get() <= 2 + 50);
+}
+
+#[test]
+fn test_minimization_from_bracket() {
+  let evaluations = Cell::new(0);
+  let f = |x: f64| {
+    evaluations.set(evaluations.get() + 1);
+    x.exp() + x * x
+  };
+
+  let (x, y) = min(&f, -2.0, 2.0, 1e-12);
+  let from_midpoint = evaluations.replace(0);
+
+  // A triplet whose middle point is close to the minimum
+  let (x_triplet, y_triplet) = min_from_bracket(&f, -2.0, -0.352, 2.0, 1e-12);
+  let from_triplet = evaluations.get();
+
+  // The location of a minimum is only determined to about sqrt(eps)
+  assert_abs_diff_eq!(x_triplet, x, epsilon = 1e-7);
+  assert_abs_diff_eq!(y_triplet, y, epsilon = 1e-15);
+  assert!(from_triplet < from_midpoint);
+}
+
+#[test]
+#[should_panic]
+fn test_minimization_from_bracket_unordered() {
+  min_from_bracket(&|x: f64| x * x, -1.0, 2.0, 1.0, 1e-12);
+}
+
+#[test]
+fn test_maximization() {
+  let f = |x: f64| -(x - 0.3).powi(2) + 1.0;
+
+  let (x, y) = max(&f, -1.0, 1.0, 1e-15);
+  assert_abs_diff_eq!(x, 0.3, epsilon = 1e-8);
+  assert_abs_diff_eq!(y, 1.0, epsilon = 1e-15);
+  assert_eq!(y, f(x));
+
+  let (x, y) = max_by_inspection(&f, -1.0, 1.0, 21);
+  assert_abs_diff_eq!(x, 0.3, epsilon = 1e-15);
+  assert_eq!(y, f(x));
+}
+
+#[test]
+fn test_minimization_with_stall() {
+  let evaluations = Cell::new(0);
+  let f = |x: f64| {
+    evaluations.set(evaluations.get() + 1);
+    (x - 0.3).powi(4) + 2.0
+  };
+
+  let (_, y) = min(&f, -1.0, 1.0, 1e-15);
+  let full = evaluations.replace(0);
+
+  let (x, y_stalled) = min_with(&f, -1.0, 1.0, 1e-15, 1e-12);
+  let stalled = evaluations.replace(0);
+
+  assert!(stalled < full);
+  assert_abs_diff_eq!(y_stalled, y, epsilon = 1e-11);
+  assert_abs_diff_eq!(x, 0.3, epsilon = 1e-2);
+
+  // A negative tolerance disables the stall test
+  assert_eq!(
+    min_with(&f, -1.0, 1.0, 1e-15, -1.0),
+    min(&f, -1.0, 1.0, 1e-15)
+  );
+}
@@ -0,0 +1,27 @@
+//! The root finders available without the `std` feature, used from a `no_std` crate.
+//!
+//! Run with `cargo test --no-default-features --test no_std`. With the `std` feature, this target is empty.
+#![cfg(not(feature = "std"))]
+#![no_std]
+
+// The test harness itself still requires std
+extern crate std;
+
+use uniarity::bracket::{bisection, itp};
+use uniarity::initial::{newtons_method, secant};
+
+#[test]
+fn test_no_std_root_finders() {
+  let f = |x: f64| x * x * x - 2.0;
+  let fp = |x: f64| 3.0 * x * x;
+  let root = 1.259_921_049_894_873_2;
+
+  for x in [
+    newtons_method(&f, &fp, 1.0, 1e-15),
+    secant(&f, 1.0, 2.0, 1e-15),
+    bisection(&f, 1.0, 2.0, 1e-15),
+    itp(&f, 1.0, 2.0, 1e-15),
+  ] {
+    assert!((x - root).abs() < 1e-14);
+  }
+}
@@ -0,0 +1,57 @@
+use approx::assert_abs_diff_eq;
+use itertools::Itertools;
+use std::f64::consts::PI;
+
+use uniarity::pchip::PchipInterp;
+
+#[test]
+fn test_interpolates_data() {
+  let xs = [0.0, 0.5, 1.7, 2.0, 3.5];
+  let ys = [1.0, -2.0, 0.5, 0.5, 4.0];
+  let interp = PchipInterp::new(&xs, &ys);
+
+  for (&x, &y) in xs.iter().zip(ys.iter()) {
+    assert_abs_diff_eq!(interp.evaluate(x), y, epsilon = 1e-15);
+  }
+}
+
+#[test]
+fn test_monotone_data() {
+  // A step in the data, where a Chebyshev approximation would ring
+  let xs: Vec<f64> = (0..20).map(|i| i as f64).collect();
+  let ys: Vec<f64> = xs
+    .iter()
+    .map(|&x| if x < 10.0 { 0.0 } else { 1.0 })
+    .collect();
+  let interp = PchipInterp::new(&xs, &ys);
+
+  let values: Vec<f64> = (0..=1900)
+    .map(|i| interp.evaluate(i as f64 / 100.0))
+    .collect();
+
+  assert!(values.iter().tuple_windows().all(|(a, b)| a <= b));
+  assert!(values.iter().all(|&y| (0.0..=1.0).contains(&y)));
+}
+
+#[test]
+fn test_roots() {
+  let xs: Vec<f64> = (0..=40).map(|i| 0.25 * i as f64).collect();
+  let ys: Vec<f64> = xs.iter().map(|&x| x.sin()).collect();
+  let interp = PchipInterp::new(&xs, &ys);
+
+  let roots = interp.roots();
+  assert_eq!(roots.len(), 4);
+  for (&x, expected) in roots.iter().zip([0.0, PI, 2.0 * PI, 3.0 * PI]) {
+    assert_abs_diff_eq!(x, expected, epsilon = 1e-2);
+    assert_abs_diff_eq!(interp.evaluate(x), 0.0, epsilon = 1e-14);
+  }
+}
+
+#[test]
+fn test_extrapolation() {
+  let interp = PchipInterp::new(&[0.0, 1.0, 2.0], &[0.0, 1.0, 2.0]);
+
+  assert_abs_diff_eq!(interp.evaluate(-1.0), -1.0, epsilon = 1e-15);
+  assert_abs_diff_eq!(interp.evaluate(3.0), 3.0, epsilon = 1e-15);
+  assert!(interp.evaluate(f64::NAN).is_nan());
+}
@@ -0,0 +1,52 @@
+use approx::assert_abs_diff_eq;
+use itertools::Itertools;
+
+use uniarity::bracket::bisection;
+use uniarity::piecewise::PiecewiseFn;
+
+#[test]
+fn test_evaluate() {
+  let f = PiecewiseFn::new(|x| x)
+    .with_piece(1.0, |x| 2.0 * x)
+    .with_piece(3.0, |_| -1.0);
+
+  assert_eq!(f.breakpoints().collect_vec(), [1.0, 3.0]);
+  assert_eq!(f.evaluate(-5.0), -5.0);
+  assert_eq!(f.evaluate(0.5), 0.5);
+  assert_eq!(f.evaluate(1.0), 2.0);
+  assert_eq!(f.evaluate(2.5), 5.0);
+  assert_eq!(f.evaluate(3.0), -1.0);
+  assert_eq!(f.evaluate(100.0), -1.0);
+}
+
+#[test]
+fn test_roots_respect_jumps() {
+  // Jumps at 1 and, from positive to negative, at 3, with genuine roots at 0.5 and 2.5
+  let f = PiecewiseFn::new(|x| 0.5 - x)
+    .with_piece(1.0, |x| x - 2.5)
+    .with_piece(3.0, |x| 2.0 - x);
+
+  let roots = f.roots(-2.0, 5.0, 0.1, 1e-15);
+  assert_eq!(roots.len(), 2);
+  assert_abs_diff_eq!(roots[0], 0.5, epsilon = 1e-15);
+  assert_abs_diff_eq!(roots[1], 2.5, epsilon = 1e-15);
+
+  // A bracketing method on a bracket spanning a jump converges onto the jump instead
+  let x = bisection(&|x| f.evaluate(x), 2.8, 4.0, 1e-15);
+  assert_abs_diff_eq!(x, 3.0, epsilon = 1e-14);
+}
+
+#[test]
+fn test_roots_at_breakpoints() {
+  // Continuous through a root at the breakpoint 1, which is reported once
+  let f = PiecewiseFn::new(|x| x - 1.0).with_piece(1.0, |x| (x - 1.0) * 3.0);
+  assert_eq!(f.roots(0.0, 2.0, 0.25, 1e-15), [1.0]);
+
+  // The first piece reaches zero at the breakpoint, but the second applies there
+  let f = PiecewiseFn::new(|x| x - 1.0).with_piece(1.0, |_| 1.0);
+  assert!(f.roots(0.0, 2.0, 0.25, 1e-15).is_empty());
+
+  // Roots at the ends of the interval
+  let f = PiecewiseFn::new(|x| x * (x - 2.0));
+  assert_eq!(f.roots(0.0, 2.0, 0.3, 1e-15), [0.0, 2.0]);
+}
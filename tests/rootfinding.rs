@@ -1,10 +1,26 @@
 use approx::assert_abs_diff_eq;
 use autodiff::{Float, F1};
-use std::f64::consts::TAU;
+use num_complex::Complex;
+use std::cell::Cell;
+use std::f64::consts::{PI, TAU};
 
-use uniarity::bracket::{bisection, itp};
+use uniarity::bracket::{
+  bisection, bisection_iterations, bracket_min_parabolic, bracket_width, brent, find_all_brackets,
+  find_bracket, find_root_bracket, find_root_bracket_with_max_extent,
+  find_root_bracket_with_max_step, find_roots, illinois, is_converged, itp, itp_iterations,
+  locate_negative, locate_negative_bounded, next_root_left, next_root_right, regula_falsi, ridders,
+  robust_root, secant_bisection_hybrid, try_bisection, try_itp, Bisection, BisectionItem,
+  BisectionIter, BracketError, BracketSolver, Brent, Illinois, Itp, ItpItem, ItpIter, RegulaFalsi,
+  Ridders, SecantBisectionHybrid,
+};
 use uniarity::cheb::Cheb;
-use uniarity::initial::{laguerres_method, newtons_method, secant};
+use uniarity::generic;
+use uniarity::initial::{
+  aitken, halleys_method, laguerres_method, mullers_method, newtons_method, newtons_method_bounded,
+  newtons_method_rel, newtons_method_report, newtons_method_with, root_sensitivity, secant,
+  secant_rel, secant_report, secant_with, steffensen, trace_root,
+};
+use uniarity::{Counted, Error, MaybeEval};
 
 struct TestCase {
   function: fn(F1) -> F1,
@@ -107,6 +123,75 @@ fn test_secant() {
   }
 }
 
+#[test]
+fn test_steffensen() {
+  for case in TESTS {
+    let f = &case.f();
+
+    // Unlike the secant method, the steps are only sensible once |f(x)| is comparable to the distance to the root,
+    // so start from a rough bracket rather than a crude guess
+    let x = bisection(f, case.a, case.b, 1e-4);
+    let x = steffensen(f, x, f64::EPSILON, 100);
+
+    let epsilon = if case.low_precision { 1e-10 } else { 1e-14 };
+    assert_abs_diff_eq!(f(x), 0.0, epsilon = epsilon);
+  }
+}
+
+#[test]
+fn test_aitken() {
+  // The Dottie number, the fixed point of cos
+  let dottie = 0.7390851332151607;
+  let g = Counted::new(|x: f64| x.cos());
+  let x = aitken(&g.as_fn(), 1.0, f64::EPSILON, 100);
+  assert_abs_diff_eq!(x, dottie, epsilon = 1e-15);
+
+  // Plain iteration converges linearly, with a rate of |sin(x)| ~ 0.67, taking around 90 evaluations
+  assert!(g.count() < 20);
+
+  // A map whose iteration reaches the fixed point exactly leaves no second difference to extrapolate from
+  let x = aitken(&|x: f64| 0.5 * x + 1.0, 2.0, f64::EPSILON, 100);
+  assert_eq!(x, 2.0);
+}
+
+#[test]
+fn test_muller() {
+  let f = |x: Complex<f64>| x * x + 1.0;
+  let i = Complex::new(0.0, 1.0);
+
+  // From real initial values, the parabola through which is exactly f, so the first step lands on a root
+  let x = mullers_method(&f, 0.5.into(), 1.0.into(), 1.5.into(), 1e-15);
+  assert!((x - i).norm() < 1e-15 || (x + i).norm() < 1e-15);
+
+  // From either side of the real axis, the nearer root
+  let x = mullers_method(
+    &f,
+    Complex::new(0.5, 0.5),
+    Complex::new(0.2, 0.7),
+    0.8 * i,
+    1e-15,
+  );
+  assert_abs_diff_eq!((x - i).norm(), 0.0, epsilon = 1e-15);
+  let x = mullers_method(
+    &f,
+    Complex::new(0.5, -0.5),
+    Complex::new(0.2, -0.7),
+    -0.8 * i,
+    1e-15,
+  );
+  assert_abs_diff_eq!((x + i).norm(), 0.0, epsilon = 1e-15);
+
+  // A real root of a cubic with a complex-conjugate pair, which the iterates leave the real axis to find
+  let f = |x: Complex<f64>| (x - 2.0) * (x * x + 2.0 * x + 5.0);
+  let x = mullers_method(&f, 0.0.into(), 0.5.into(), 1.0.into(), 1e-15);
+  let roots = [
+    Complex::new(2.0, 0.0),
+    Complex::new(-1.0, 2.0),
+    Complex::new(-1.0, -2.0),
+  ];
+  assert!(roots.iter().any(|root| (x - root).norm() < 1e-14));
+}
+
 #[test]
 fn test_newton() {
   for case in TESTS {
@@ -122,6 +207,121 @@ fn test_newton() {
   }
 }
 
+#[test]
+fn test_newton_flat_point() {
+  // The derivative vanishes at sqrt(2 / 3), where f is far from zero; the only real root is near -1.77
+  let f = |x: f64| x.powi(3) - 2.0 * x + 2.0;
+  let g = |x: f64| 3.0 * x * x - 2.0;
+  let x0 = (2.0_f64 / 3.0).sqrt();
+  assert!(g(x0).abs() <= 1e-12 && f(x0) > 0.9);
+
+  // Previously, the flat point itself was returned
+  let report = newtons_method_report(&f, &g, x0, 1e-12);
+  assert!(report.converged);
+  assert_abs_diff_eq!(report.root, -1.7692923542386314, epsilon = 1e-12);
+  assert_abs_diff_eq!(f(report.root), 0.0, epsilon = 1e-12);
+
+  // A root of high multiplicity with the derivative vanishing at the initial value
+  let f = |x: f64| -x.powi(11) + 1e-10;
+  let g = |x: f64| -11.0 * x.powi(10);
+  let x = newtons_method(&f, &g, 0.0, f64::EPSILON);
+  // The root is so flat that a residual at the resolution of f leaves its location uncertain to about 1e-11
+  assert_abs_diff_eq!(x, 1e-10_f64.powf(1.0 / 11.0), epsilon = 1e-9);
+}
+
+#[test]
+fn test_iteration_caps() {
+  // A root of high multiplicity, towards which both methods converge only linearly
+  let evaluations = Cell::new(0);
+  let f = |x: f64| {
+    evaluations.set(evaluations.get() + 1);
+    (x - 1.0).powi(15)
+  };
+  let g = |x: f64| 15.0 * (x - 1.0).powi(14);
+
+  let x = newtons_method_with(&f, &g, 2.0, 0.0, 10);
+  assert!(x.is_finite() && x > 1.0);
+  assert_eq!(evaluations.get(), 11);
+
+  evaluations.set(0);
+  let x = secant_with(&f, 2.0, 1.9, 0.0, 10);
+  assert!(x.is_finite() && x > 1.0);
+  assert_eq!(evaluations.get(), 12);
+
+  // The default cap allows progressively closer iterates
+  assert!(newtons_method(&f, &g, 2.0, 0.0) < newtons_method_with(&f, &g, 2.0, 0.0, 10));
+}
+
+#[test]
+fn test_root_sensitivity() {
+  // The root sqrt(p) of x^2 - p moves at the rate 1 / (2 sqrt(p))
+  let f = |x: f64, p: f64| x * x - p;
+  for p in [0.25_f64, 2.0, 1e4] {
+    let dx_dp = root_sensitivity(&f, p.sqrt(), p, 1e-12);
+    assert_abs_diff_eq!(dx_dp, 0.5 / p.sqrt(), epsilon = 1e-8 * p.sqrt().recip());
+  }
+
+  // At the turning point where the two roots meet, the sensitivity is unbounded
+  assert!(root_sensitivity(&f, 0.0, 0.0, 1e-12).is_nan());
+
+  // Agrees with the rate at which a traced root moves
+  let g = |x: f64, t: f64| x.cos() - t * x;
+  let ts = [1.0, 1.0 + 1e-6];
+  let roots = trace_root(&g, 0.7, &ts, 1e-15);
+  let traced = (roots[1] - roots[0]) / 1e-6;
+  assert_abs_diff_eq!(
+    root_sensitivity(&g, roots[0], 1.0, 1e-12),
+    traced,
+    epsilon = 1e-5
+  );
+}
+
+#[test]
+fn test_convergence_reports() {
+  for case in TESTS.iter().filter(|case| !case.low_precision) {
+    let f = &case.f();
+    let fp = &case.fp();
+    let x = (case.a + case.b) / 2.0;
+
+    let report = newtons_method_report(f, fp, x, f64::EPSILON);
+    assert!(report.converged);
+    assert_eq!(report.root, newtons_method(f, fp, x, f64::EPSILON));
+    assert_eq!(report.final_residual, f(report.root));
+
+    let report = secant_report(f, x, x + 1e-6, f64::EPSILON);
+    assert!(report.converged);
+    assert_eq!(report.root, secant(f, x, x + 1e-6, f64::EPSILON));
+    assert!(report.iterations < 100);
+  }
+
+  // Diverging along the flat tails of atan, where neither method finds the root at zero
+  let f = |x: f64| x.atan();
+  let g = |x: f64| 1.0 / (1.0 + x * x);
+
+  let report = newtons_method_report(&f, &g, 2.0, f64::EPSILON);
+  assert!(!report.converged);
+  assert!(report.final_residual.abs() > 1.0);
+
+  let report = secant_report(&f, 3.0, 3.5, f64::EPSILON);
+  assert!(!report.converged);
+}
+
+#[test]
+fn test_halley() {
+  for case in TESTS {
+    let f = &case.f();
+    let fp = &case.fp();
+    let fpp = &case.fpp();
+
+    // A very crude initial guess
+    let x = (case.a + case.b) / 2.0;
+    let x = halleys_method(f, fp, fpp, x, f64::EPSILON);
+
+    let epsilon = if case.low_precision { 1e-10 } else { 1e-15 };
+    assert_abs_diff_eq!(f(x), 0.0, epsilon = epsilon);
+  }
+}
+
 #[test]
 fn test_laguerre() {
   for case in TESTS {
@@ -136,6 +336,13 @@ fn test_laguerre() {
     let epsilon = if case.low_precision { 1e-10 } else { 1e-15 };
     assert_abs_diff_eq!(f(x), 0.0, epsilon = epsilon);
   }
+
+  // At x = 0, the discriminant of (x^2 + 1)(x - 3) is negative, as the complex roots ±i are nearest
+  let f = |x: f64| (x * x + 1.0) * (x - 3.0);
+  let fp = |x: f64| 3.0 * x * x - 6.0 * x + 1.0;
+  let fpp = |x: f64| 6.0 * x - 6.0;
+  let x = laguerres_method(&f, &fp, &fpp, 3.0, 0.0, f64::EPSILON);
+  assert_abs_diff_eq!(x, 3.0, epsilon = 1e-15);
 }
 
 #[test]
@@ -156,6 +363,89 @@ fn test_itp() {
   }
 }
 
+#[test]
+fn test_regula_falsi() {
+  for case in TESTS {
+    let f = &case.f();
+    let x = regula_falsi(f, case.a, case.b, f64::EPSILON);
+
+    let epsilon = if case.low_precision { 1e-10 } else { 1e-14 };
+    assert_abs_diff_eq!(f(x), 0.0, epsilon = epsilon);
+  }
+}
+
+#[test]
+fn test_illinois() {
+  for case in TESTS {
+    let f = &case.f();
+    let x = illinois(f, case.a, case.b, f64::EPSILON);
+    assert_abs_diff_eq!(f(x), 0.0, epsilon = 1e-14);
+  }
+}
+
+#[test]
+fn test_illinois_convex() {
+  // The function is convex, so every step of plain false position lands left of the root, and the right end of the
+  // bracket is never moved
+  let f = Counted::new(|x: f64| x.exp() - 2.0);
+  let x = regula_falsi(&f.as_fn(), 0.0, 2.0, 1e-12);
+  assert_abs_diff_eq!(x, 2f64.ln(), epsilon = 1e-10);
+  let regula_falsi_count = f.count();
+
+  f.reset();
+  let x = illinois(&f.as_fn(), 0.0, 2.0, 1e-12);
+  assert_abs_diff_eq!(x, 2f64.ln(), epsilon = 1e-12);
+  assert!(f.count() < 20);
+  assert!(2 * f.count() < regula_falsi_count);
+}
+
+#[test]
+fn test_generic_precisions() {
+  for case in TESTS {
+    let f = &case.f();
+    let fp = &case.fp();
+    let x = (case.a + case.b) / 2.0;
+
+    // In f64, the generic methods agree exactly with the methods of the other modules
+    let bisection_64 = generic::bisection(f, case.a, case.b, f64::EPSILON);
+    let itp_64 = generic::itp(f, case.a, case.b, f64::EPSILON);
+    let newton_64 = generic::newtons_method(f, fp, x, f64::EPSILON);
+    let secant_64 = generic::secant(f, x, x + 1e-6, f64::EPSILON);
+    assert_eq!(bisection_64, bisection(f, case.a, case.b, f64::EPSILON));
+    assert_eq!(itp_64, itp(f, case.a, case.b, f64::EPSILON));
+    assert_eq!(newton_64, newtons_method(f, fp, x, f64::EPSILON));
+    assert_eq!(secant_64, secant(f, x, x + 1e-6, f64::EPSILON));
+
+    // The secant method stops short of the flat root of high multiplicity, at a point which depends on the precision
+    if case.low_precision {
+      continue;
+    }
+
+    // In f32, they locate the same roots to single precision
+    let f_32 = &|x: f32| f(x as f64) as f32;
+    let fp_32 = &|x: f32| fp(x as f64) as f32;
+    let (a, b, x) = (case.a as f32, case.b as f32, x as f32);
+    // A step of 1e-6 would be lost to rounding in f32
+    let dx = 1e-3 * x.abs().max(1.0);
+    let roots_32 = [
+      (generic::bisection(f_32, a, b, f32::EPSILON), bisection_64),
+      (generic::itp(f_32, a, b, f32::EPSILON), itp_64),
+      (
+        generic::newtons_method(f_32, fp_32, x, f32::EPSILON),
+        newton_64,
+      ),
+      (generic::secant(f_32, x, x + dx, f32::EPSILON), secant_64),
+    ];
+    for (root_32, root_64) in roots_32 {
+      assert_abs_diff_eq!(
+        root_32 as f64,
+        root_64,
+        epsilon = 1e-6 * root_64.abs().max(1.0)
+      );
+    }
+  }
+}
+
 #[test]
 fn test_cheb() {
   for case in TESTS {
@@ -177,3 +467,813 @@ fn test_cheb() {
     }
   }
 }
+
+#[test]
+fn test_itp_iter() {
+  for case in TESTS {
+    let f = &case.f();
+
+    let (mut a, mut b) = (case.a, case.b);
+    let mut root = None;
+    for item in ItpIter::new(f, case.a, case.b, f64::EPSILON) {
+      assert!(root.is_none());
+      match item {
+        ItpItem::Bracket {
+          a: a_next,
+          b: b_next,
+          ..
+        } => {
+          assert!(a <= a_next && b_next <= b && a_next < b_next);
+          (a, b) = (a_next, b_next);
+        }
+        ItpItem::Root(x) => root = Some(x),
+      }
+    }
+
+    assert_eq!(root, Some(itp(f, case.a, case.b, f64::EPSILON)));
+  }
+}
+
+#[test]
+fn test_itp_stall() {
+  // At this scale the truncation is far below the resolution of x, and the interpolated point is the root to within
+  // rounding, so without a fallback the point evaluated would repeatedly round onto an end of the bracket
+  let f = |x: f64| (x - 1e6 - 0.3) * 1e-300;
+
+  let (mut a, mut b) = (0.0, 1e7);
+  let mut iterations = 0;
+  for item in ItpIter::new(&f, a, b, f64::EPSILON) {
+    if let ItpItem::Bracket {
+      a: a_next,
+      b: b_next,
+      ..
+    } = item
+    {
+      assert!(b_next - a_next < b - a);
+      (a, b) = (a_next, b_next);
+      iterations += 1;
+    }
+  }
+
+  assert!(iterations <= itp_iterations(0.0, 1e7, f64::EPSILON));
+  assert_abs_diff_eq!(itp(&f, 0.0, 1e7, f64::EPSILON), 1e6 + 0.3, epsilon = 1e-8);
+}
+
+#[test]
+fn test_degenerate_tolerances() {
+  for case in TESTS {
+    let f = &case.f();
+
+    for tol in [0.0, -1.0, f64::NAN] {
+      let x = bisection(f, case.a, case.b, tol);
+      assert_abs_diff_eq!(f(x), 0.0, epsilon = 1e-14);
+
+      let x = itp(f, case.a, case.b, tol);
+      assert_abs_diff_eq!(f(x), 0.0, epsilon = 1e-14);
+    }
+  }
+}
+
+#[test]
+fn test_itp_resume() {
+  for case in TESTS {
+    let f = &case.f();
+
+    let mut iter = ItpIter::new(f, case.a, case.b, f64::EPSILON);
+    iter.by_ref().take(3).for_each(drop);
+
+    let state = iter.state();
+    let resumed = ItpIter::resume(f, state).last();
+    assert_eq!(resumed, iter.last());
+    assert_eq!(
+      resumed,
+      Some(ItpItem::Root(itp(f, case.a, case.b, f64::EPSILON)))
+    );
+  }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_itp_serialized_resume() {
+  use uniarity::bracket::ItpState;
+
+  for case in TESTS {
+    let f = &case.f();
+
+    let mut iter = ItpIter::new(f, case.a, case.b, f64::EPSILON);
+    iter.by_ref().take(3).for_each(drop);
+
+    let json = serde_json::to_string(&iter.state()).unwrap();
+    let state: ItpState = serde_json::from_str(&json).unwrap();
+    assert_eq!(state, iter.state());
+    assert_eq!(ItpIter::resume(f, state).last(), iter.last());
+  }
+
+  // Brackets which are already converged, or become so, without any unknown function values left in the state
+  let f = |x: f64| x - 0.5;
+  for mut iter in [
+    ItpIter::new(&f, 0.5, 0.5, 1e-10),
+    ItpIter::new(&f, 0.5, 0.5 + 1e-12, 1e-10),
+    ItpIter::new(&f, 0.0, 1.0, 1e-10),
+  ] {
+    iter.by_ref().for_each(drop);
+
+    let json = serde_json::to_string(&iter.state()).unwrap();
+    let state: ItpState = serde_json::from_str(&json).unwrap();
+    assert_eq!(state, iter.state());
+    assert_eq!(ItpIter::resume(&f, state).next(), None);
+  }
+  for tol in [1e-10, f64::EPSILON] {
+    let iter = ItpIter::new(&f, 0.5, 0.5, tol);
+    let json = serde_json::to_string(&iter.state()).unwrap();
+    let state: ItpState = serde_json::from_str(&json).unwrap();
+    assert_eq!(ItpIter::resume(&f, state).last(), Some(ItpItem::Root(0.5)));
+  }
+}
+
+#[test]
+fn test_bisection_resume() {
+  for case in TESTS {
+    let f = &case.f();
+
+    let mut iter = BisectionIter::new(f, case.a, case.b, f64::EPSILON);
+    iter.by_ref().take(3).for_each(drop);
+
+    let state = iter.state();
+    let resumed = BisectionIter::resume(f, state).last();
+    assert_eq!(resumed, iter.last());
+    assert_eq!(
+      resumed,
+      Some(BisectionItem::Root(bisection(
+        f,
+        case.a,
+        case.b,
+        f64::EPSILON
+      )))
+    );
+  }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_bisection_serialized_resume() {
+  use uniarity::bracket::BisectionState;
+
+  for case in TESTS {
+    let f = &case.f();
+
+    let mut iter = BisectionIter::new(f, case.a, case.b, f64::EPSILON);
+    iter.by_ref().take(3).for_each(drop);
+
+    let json = serde_json::to_string(&iter.state()).unwrap();
+    let state: BisectionState = serde_json::from_str(&json).unwrap();
+    assert_eq!(state, iter.state());
+    assert_eq!(BisectionIter::resume(f, state).last(), iter.last());
+  }
+
+  // A bracket which is already converged, whose function value is never evaluated
+  let f = |x: f64| x - 0.5;
+  let iter = BisectionIter::new(&f, 0.5, 0.5 + 1e-12, 1e-10);
+  let json = serde_json::to_string(&iter.state()).unwrap();
+  let state: BisectionState = serde_json::from_str(&json).unwrap();
+  assert_eq!(state, iter.state());
+  assert_eq!(
+    BisectionIter::resume(&f, state).last(),
+    Some(BisectionItem::Root(0.5 + 0.5e-12))
+  );
+}
+
+#[test]
+fn test_trace_root() {
+  let ts: Vec<f64> = (0..=20).map(|i| 1.0 + 0.05 * i as f64).collect();
+  let roots = trace_root(&|x: f64, t: f64| x * x * x - t, 1.0, &ts, 1e-15);
+
+  assert_eq!(roots.len(), ts.len());
+  for (&x, &t) in roots.iter().zip(&ts) {
+    assert_abs_diff_eq!(x, t.cbrt(), epsilon = 1e-12);
+  }
+
+  // The root is stationary between the first two parameter values, which does not make the next step a jump
+  let ts = [-0.1, 0.1, 0.2];
+  let roots = trace_root(&|x: f64, t: f64| x - t * t, 0.0, &ts, 1e-15);
+  for (&x, &t) in roots.iter().zip(&ts) {
+    assert_abs_diff_eq!(x, t * t, epsilon = 1e-12);
+  }
+}
+
+#[test]
+fn test_trace_root_fold() {
+  // The branch x = sqrt(t) ends at the fold t = 0
+  let ts: Vec<f64> = (0..=20).map(|i| 1.0 - 0.1 * i as f64).collect();
+  let roots = trace_root(&|x: f64, t: f64| x * x - t, 1.0, &ts, 1e-15);
+
+  assert_eq!(roots.len(), ts.len());
+  for (&x, &t) in roots.iter().zip(&ts) {
+    if t > 0.05 {
+      assert_abs_diff_eq!(x, t.sqrt(), epsilon = 1e-9);
+    } else if t < -0.05 {
+      assert!(x.is_nan());
+    }
+  }
+
+  // Repeated parameter values neither disable nor trigger the detection of the jump between branches of
+  // x^3 - x = t at t = -2 / (3 sqrt(3)), where the branch through x = 1 ends
+  let ts: Vec<f64> = (0..=12).flat_map(|i| [-0.1 * i as f64; 2]).collect();
+  let roots = trace_root(&|x: f64, t: f64| x.powi(3) - x - t, 1.0, &ts, 1e-15);
+  for (&x, &t) in roots.iter().zip(&ts) {
+    if t > -0.35 {
+      assert_abs_diff_eq!(x.powi(3) - x, t, epsilon = 1e-12);
+      assert!(x > 0.5);
+    } else if t < -0.45 {
+      assert!(x.is_nan());
+    }
+  }
+}
+
+#[test]
+fn test_find_bracket_at_zero() {
+  let f = |x: f64| (x - 1.0).powi(2);
+  let (a, b) = find_bracket(&f, 1.0, -10.0, 10.0, 0.1).unwrap();
+  assert_eq!((a.x(), a.fx()), (1.0, Some(0.0)));
+  assert_eq!((b.x(), b.fx()), (1.0, Some(0.0)));
+}
+
+#[test]
+fn test_find_bracket_decreasing() {
+  // Decreasing throughout, so no bracket exists. With unbounded limits, the search runs until it overflows
+  let f = |x: f64| (-x).exp();
+  assert!(find_bracket(&f, 0.0, -10.0, 10.0, 0.1).is_none());
+  assert!(find_bracket(&f, 0.0, f64::NEG_INFINITY, f64::INFINITY, 0.1).is_none());
+
+  // A zero step never leaves the starting point
+  let f = |x: f64| 1.0 + (x - 5.0).powi(2);
+  assert!(find_bracket(&f, 0.0, -10.0, 10.0, 0.0).is_none());
+}
+
+#[test]
+fn test_estimated_iterations() {
+  for case in TESTS {
+    let f = &case.f();
+
+    let evaluations = Cell::new(0);
+    let counted = |x: f64| {
+      evaluations.set(evaluations.get() + 1);
+      f(x)
+    };
+
+    bisection(&counted, case.a, case.b, 1e-12);
+    assert_eq!(
+      evaluations.get(),
+      bisection_iterations(case.a, case.b, 1e-12) + 1
+    );
+
+    let iterations = ItpIter::new(f, case.a, case.b, 1e-12).count() - 1;
+    assert!(iterations <= itp_iterations(case.a, case.b, 1e-12));
+  }
+}
+
+#[test]
+fn test_extreme_magnitudes() {
+  let f = |x: f64| x.powi(3) - x + 0.6;
+  let expected: Vec<ItpItem> = ItpIter::new(&f, -2.0, 2.0, f64::EPSILON).collect();
+
+  // Scaling by a power of two is exact, so the iterations must be identical
+  for scale in [2f64.powi(1000), 2f64.powi(-1000)] {
+    let g = |x: f64| scale * f(x);
+    let items: Vec<ItpItem> = ItpIter::new(&g, -2.0, 2.0, f64::EPSILON).collect();
+    assert_eq!(items, expected);
+
+    assert_eq!(
+      bisection(&g, -2.0, 2.0, f64::EPSILON),
+      bisection(&f, -2.0, 2.0, f64::EPSILON)
+    );
+  }
+}
+
+#[test]
+fn test_newton_bounded() {
+  // Unbounded, Newton's method on atan overshoots and diverges from this initial value
+  let f = |x: f64| x.atan();
+  let g = |x: f64| 1.0 / (1.0 + x * x);
+  assert!(newtons_method(&f, &g, 2.0, f64::EPSILON).abs() > 1e3);
+
+  let x = newtons_method_bounded(&f, &g, -1.2, 1.2, 2.0, f64::EPSILON);
+  assert_abs_diff_eq!(x, 0.0, epsilon = 1e-15);
+
+  // Bounds which do not contain a root leave the iterate on the nearest bound
+  let x = newtons_method_bounded(
+    &|x: f64| x * x + 1.0,
+    &|x: f64| 2.0 * x,
+    1.0,
+    3.0,
+    2.0,
+    1e-12,
+  );
+  assert_eq!(x, 1.0);
+}
+
+#[test]
+fn test_newton_relative() {
+  // The same root, on functions and intervals of very different scales
+  for scale in [1e-9, 1.0, 1e9] {
+    let f = |x: f64| scale * ((x / 1e6) - (x / 1e6).sin() - 1.2);
+    let g = |x: f64| scale * (1.0 - (x / 1e6).cos()) / 1e6;
+
+    let x0 = 1e6 * TAU / 2.0;
+    let x = newtons_method_rel(&f, &g, x0, 1e-12);
+    assert_abs_diff_eq!(x / 1e6, 2.07538, epsilon = 1e-5);
+    assert!(f(x).abs() <= 1e-12 * f(x0).abs());
+  }
+
+  // A root at zero, where only the residual criterion can be met
+  let x = newtons_method_rel(
+    &|x: f64| x.powi(3) + x,
+    &|x: f64| 3.0 * x * x + 1.0,
+    0.5,
+    1e-12,
+  );
+  assert_abs_diff_eq!(x, 0.0, epsilon = 1e-12);
+}
+
+#[test]
+fn test_secant_relative() {
+  for root in [1e-12, 1.0, 1e9] {
+    let f = |x: f64| x * x - root * root;
+    let x = secant_rel(&f, 1.5 * root, 1.4 * root, 1e-12);
+    assert_abs_diff_eq!(x / root, 1.0, epsilon = 1e-12);
+
+    // Newton's method with the relative tolerance agrees
+    let y = newtons_method_rel(&f, &|x: f64| 2.0 * x, 1.5 * root, 1e-12);
+    assert_abs_diff_eq!(x / root, y / root, epsilon = 1e-12);
+  }
+
+  // Near 1e9, rounding keeps |f| around 1e2, so a residual below an absolute tolerance of 1e-12 is never reached,
+  // while the relative step test ends the iteration quickly
+  let f = Counted::new(|x: f64| x * x - 1e18);
+  let x = secant_rel(&f.as_fn(), 1.5e9, 1.4e9, 1e-12);
+  assert!(f.count() < 20);
+  assert!(f.eval(x).abs() > 1e-12);
+  assert_abs_diff_eq!(x, 1e9, epsilon = 1e-3);
+}
+
+#[test]
+fn test_bracket_min_parabolic() {
+  let calls = Cell::new(0);
+  let f = |x: f64| {
+    calls.set(calls.get() + 1);
+    (x - 10.0).powi(2) + 1.0
+  };
+
+  let (a, b, c) = bracket_min_parabolic(&f, 0.0, 0.1);
+  let parabolic_calls = calls.get();
+  assert!(a < b && b < c);
+  assert!(f(b) <= f(a) && f(b) <= f(c));
+  assert!(a <= 10.0 && 10.0 <= c);
+
+  calls.set(0);
+  find_bracket(&f, 0.0, -100.0, 100.0, 0.1).unwrap();
+  assert!(parabolic_calls < calls.get());
+
+  // Collinear points, starting from the uphill side, where the parabola through them is degenerate
+  let f = |x: f64| (x - 50.0).abs();
+  let (a, b, c) = bracket_min_parabolic(&f, 1.0, 0.0);
+  assert!(a < b && b < c);
+  assert!(f(b) <= f(a) && f(b) <= f(c));
+  assert!(a <= 50.0 && 50.0 <= c);
+}
+
+#[test]
+fn test_guarantees() {
+  fn check_guarantees<S: BracketSolver>(solver: S) {
+    let guarantees = S::guarantees();
+    assert!(guarantees.bracketing);
+
+    for (a, b, tol) in [(0.0, 2.0, 1e-15), (-1e3, 1e5, 1e-8), (1.0, 1.5, 1e-3)] {
+      let calls = Cell::new(0);
+      let f = |x: f64| {
+        calls.set(calls.get() + 1);
+        (x - 1.3).powi(3) + 1e-3 * (x - 1.3)
+      };
+
+      let x = solver.solve(&f, a, b, tol);
+      assert!(a <= x && x <= b);
+      if let Some(worst_case_iterations) = guarantees.worst_case_iterations {
+        // The endpoints are evaluated on top of the iterations
+        assert!(calls.get() <= worst_case_iterations(a, b, tol) + 2);
+      }
+    }
+  }
+
+  check_guarantees(Bisection);
+  check_guarantees(Itp);
+  check_guarantees(Brent);
+  check_guarantees(Ridders);
+  check_guarantees(SecantBisectionHybrid);
+  check_guarantees(RegulaFalsi);
+  check_guarantees(Illinois);
+  assert!(Bisection::guarantees().worst_case_iterations.is_some());
+  assert!(Brent::guarantees().worst_case_iterations.is_none());
+}
+
+#[test]
+fn test_find_all_brackets() {
+  // The roots are 0, π, 2π and 3π, where the root at 0 lies on the first point sampled
+  let f = Counted::new(|x: f64| x.sin());
+  let brackets = find_all_brackets(&f.as_fn(), 0.0, 10.0, 100);
+  assert_eq!(f.count(), 101);
+  assert_eq!(brackets.len(), 4);
+
+  for (k, &(a, b)) in brackets.iter().enumerate() {
+    assert_abs_diff_eq!(b - a, 0.1, epsilon = 1e-12);
+    let x = itp(&f.as_fn(), a, b, f64::EPSILON);
+    assert_abs_diff_eq!(x, k as f64 * PI, epsilon = 1e-12);
+  }
+
+  assert!(find_all_brackets(&|x: f64| x * x + 1.0, -1.0, 1.0, 10).is_empty());
+  assert!(find_all_brackets(&|x: f64| x, -1.0, 1.0, 0).is_empty());
+}
+
+#[test]
+fn test_find_roots() {
+  // Cases with a single well-conditioned root, which a high degree Chebyshev approximation locates accurately
+  for case in [&TESTS[2], &TESTS[3], &TESTS[7]] {
+    let f = &case.f();
+    let roots = find_roots(f, case.a, case.b);
+    let cheb_roots = Cheb::new(f, case.a, case.b, case.n + 40).roots();
+
+    assert_eq!(roots.len(), cheb_roots.len());
+    for (&x, &cheb_x) in roots.iter().zip(&cheb_roots) {
+      assert_abs_diff_eq!(x, cheb_x, epsilon = 1e-8);
+      assert_abs_diff_eq!(f(x), 0.0, epsilon = 1e-14);
+    }
+  }
+
+  let roots = find_roots(&|x: f64| x.sin(), -0.5, 10.0);
+  assert_eq!(roots.len(), 4);
+  for (k, &x) in roots.iter().enumerate() {
+    assert_abs_diff_eq!(x, k as f64 * PI, epsilon = 1e-14);
+  }
+}
+
+#[test]
+fn test_next_root() {
+  let f = |x: f64| x.sin();
+
+  let x = next_root_right(&f, 0.5, 0.3, 10.0, f64::EPSILON).unwrap();
+  assert_abs_diff_eq!(x, PI, epsilon = 1e-14);
+  let x = next_root_right(&f, x + 0.1, 0.3, 10.0, f64::EPSILON).unwrap();
+  assert_abs_diff_eq!(x, 2.0 * PI, epsilon = 1e-14);
+
+  let x = next_root_left(&f, 5.0, 0.3, -10.0, f64::EPSILON).unwrap();
+  assert_abs_diff_eq!(x, PI, epsilon = 1e-14);
+  let x = next_root_left(&f, -0.5, 0.3, -10.0, f64::EPSILON).unwrap();
+  assert_abs_diff_eq!(x, -PI, epsilon = 1e-14);
+
+  // A root at the starting point
+  assert_eq!(next_root_right(&f, 0.0, 0.3, 10.0, f64::EPSILON), Some(0.0));
+
+  // The search stops at the limit, which is itself checked
+  assert_eq!(next_root_right(&f, 0.5, 0.3, 3.0, f64::EPSILON), None);
+  let x = next_root_left(&f, 5.0, 0.3, 3.0, f64::EPSILON).unwrap();
+  assert_abs_diff_eq!(x, PI, epsilon = 1e-14);
+  assert_eq!(next_root_left(&f, 5.0, 0.3, 3.2, f64::EPSILON), None);
+}
+
+#[test]
+fn test_locate_negative_bounded() {
+  let evaluations = Cell::new(0);
+  let f = |x: f64| {
+    evaluations.set(evaluations.get() + 1);
+    (x - 3.0).powi(2) - 1e-12
+  };
+
+  // The endpoints, and then two evaluations per iteration
+  assert!(locate_negative_bounded(f, -1e3, 1e3, 1e-15, 10).is_none());
+  assert_eq!(evaluations.get(), 2 + 2 * 10);
+
+  let x = locate_negative_bounded(f, -1e3, 1e3, 1e-15, 100).unwrap();
+  assert!(x.evaled(&f).1 < 0.0);
+  assert_eq!(locate_negative(f, -1e3, 1e3, 1e-15).unwrap().x(), x.x());
+}
+
+#[test]
+fn test_find_root_bracket_max_step() {
+  // Two close roots near 7.05 and 7.15, which doubling steps from 0 pass over together
+  let f = |x: f64| (x - 7.0) * (x - 7.2) + 1e-3 * x;
+  assert!(find_root_bracket(&f, 0.0, 0.1).is_none());
+
+  let (a, b) = find_root_bracket_with_max_step(&f, 0.0, 0.1, 0.1).unwrap();
+  assert!(a.x() < b.x());
+  assert!(f(a.x()).signum() != f(b.x()).signum());
+  assert!(a.x() >= 6.9 && b.x() <= 7.2);
+}
+
+#[test]
+#[should_panic]
+fn test_find_root_bracket_negative_max_step() {
+  find_root_bracket_with_max_step(&|x: f64| x - 1.0, 0.0, 0.1, -0.1);
+}
+
+#[test]
+#[should_panic]
+fn test_find_root_bracket_nan_max_step() {
+  find_root_bracket_with_max_step(&|x: f64| x - 1.0, 0.0, 0.1, f64::NAN);
+}
+
+#[test]
+fn test_find_root_bracket_non_finite() {
+  // No real root: the search overflows in either direction without a sign change
+  let f = |x: f64| x.exp();
+  assert!(find_root_bracket(&f, 0.0, 0.1).is_none());
+  assert!(find_root_bracket(&f, 0.0, -0.1).is_none());
+  assert!(find_root_bracket(&f, 0.0, 1e300).is_none());
+
+  // NaN once the step overflows, which must not be mistaken for a sign change
+  let f = |x: f64| x.sin() + 2.0;
+  assert!(find_root_bracket(&f, 0.0, 1.0).is_none());
+
+  // NaN or a non-finite point at the start of the search, which has no sign to compare against
+  let f = |x: f64| if x == 0.0 { f64::NAN } else { x - 5.0 };
+  assert!(find_root_bracket(&f, 0.0, 1.0).is_none());
+  assert!(find_root_bracket(&|x: f64| x - 5.0, (0.0, f64::NAN), 1.0).is_none());
+  assert!(find_root_bracket(&|x: f64| x - 5.0, f64::NEG_INFINITY, 1.0).is_none());
+  assert!(find_root_bracket(&|x: f64| x - 5.0, f64::NAN, 1.0).is_none());
+
+  // A step lost to rounding cannot make progress
+  assert!(find_root_bracket(&|x: f64| x * x + 1.0, 0.0, 0.0).is_none());
+
+  // The search stops at its extent, with the last step shortened to reach it
+  let f = |x: f64| x - 10.0;
+  assert!(find_root_bracket_with_max_extent(&f, 0.0, 0.1, f64::INFINITY, 9.0).is_none());
+  let (a, b) = find_root_bracket_with_max_extent(&f, 0.0, 0.1, f64::INFINITY, 10.0).unwrap();
+  assert_eq!((b.x(), b.fx()), (10.0, Some(0.0)));
+  assert!(a.x() < 10.0);
+}
+
+#[test]
+fn test_maybe_eval() {
+  let a = MaybeEval::known(1.0, 0.5);
+  let b = MaybeEval::unknown(2.0);
+  assert!(a.is_known() && !b.is_known());
+  assert_eq!(a, MaybeEval::from((1.0, 0.5)));
+  assert_eq!(b, MaybeEval::from(2.0));
+  assert_eq!(MaybeEval::from(&a), a);
+  assert_eq!((a.x(), a.fx(), b.x(), b.fx()), (1.0, Some(0.5), 2.0, None));
+
+  // The same endpoints may be reused across methods, and a known value is used rather than recomputed
+  let f = |x: f64| x - 1.5;
+  let (a, b) = (MaybeEval::known(1.0, -0.5), MaybeEval::unknown(2.0));
+  assert_abs_diff_eq!(bisection(&f, a, b, f64::EPSILON), 1.5, epsilon = 1e-15);
+  assert_abs_diff_eq!(itp(&f, a, b, f64::EPSILON), 1.5, epsilon = 1e-15);
+  assert_eq!(a.evaled(|_| unreachable!()), (1.0, -0.5));
+
+  let f32_eval = MaybeEval::known(1.0f32, 2.0f32);
+  assert_eq!(f32_eval.evaled(|x| x), (1.0, 2.0));
+}
+
+#[test]
+fn test_non_finite_inputs() {
+  let f = |x: f64| x - 0.5;
+
+  assert!(MaybeEval::from(1.0).is_finite());
+  assert!(MaybeEval::from((1.0, 0.5)).is_finite());
+  assert!(!MaybeEval::from(f64::INFINITY).is_finite());
+  assert!(!MaybeEval::from((1.0, f64::NAN)).is_finite());
+
+  assert_eq!(
+    try_bisection(&f, 0.0, 1.0, 1e-15),
+    Ok(bisection(&f, 0.0, 1.0, 1e-15))
+  );
+  assert_eq!(try_itp(&f, 0.0, 1.0, 1e-15), Ok(itp(&f, 0.0, 1.0, 1e-15)));
+
+  assert_eq!(
+    try_bisection(&f, (0.0, f64::NAN), 1.0, 1e-15),
+    Err(Error::NonFinite)
+  );
+  assert_eq!(
+    try_itp(&f, 0.0, f64::INFINITY, 1e-15),
+    Err(Error::NonFinite)
+  );
+}
+
+#[test]
+fn test_counted_evaluations() {
+  let f = Counted::new(|x: f64| x * x.exp() - 1.0);
+  let g = f.as_fn();
+  assert_eq!(f.count(), 0);
+
+  let x = bisection(&g, 0.0, 2.0, 1e-15);
+  assert_eq!(f.count(), bisection_iterations(0.0, 2.0, 1e-15) + 1);
+
+  f.reset();
+  assert_abs_diff_eq!(itp(&g, 0.0, 2.0, 1e-15), x, epsilon = 1e-14);
+  let itp_count = f.count();
+  assert!(0 < itp_count && itp_count < bisection_iterations(0.0, 2.0, 1e-15));
+
+  f.reset();
+  assert_abs_diff_eq!(brent(&g, 0.0, 2.0, 1e-15), x, epsilon = 1e-14);
+  assert!(f.count() > 0);
+}
+
+#[test]
+fn test_invalid_brackets() {
+  let f = |x: f64| x - 0.5;
+
+  assert_eq!(try_bisection(&f, 0.6, 1.0, 1e-15), Err(Error::SameSign));
+  assert_eq!(
+    try_itp(&f, (0.0, -0.5), (0.4, -0.1), 1e-15),
+    Err(Error::SameSign)
+  );
+  assert_eq!(try_bisection(&f, 1.0, 0.0, 1e-15), Err(Error::NotOrdered));
+  assert_eq!(
+    try_bisection(&f, 0.6, 1.0, 1e-15),
+    Err(BracketError::SameSign)
+  );
+  assert_eq!(try_itp(&f, 0.5, 0.5, 1e-15), Err(Error::ZeroWidth));
+
+  // A root at an end of the bracket is valid
+  assert_abs_diff_eq!(try_itp(&f, 0.5, 1.0, 1e-15).unwrap(), 0.5, epsilon = 1e-14);
+
+  // A function value which is NaN is found when the endpoint is evaluated
+  assert_eq!(
+    try_bisection(&|x: f64| (x - 0.5).sqrt(), 0.0, 1.0, 1e-15),
+    Err(Error::NonFinite)
+  );
+
+  // The endpoints are only evaluated once
+  let evaluations = Cell::new(0);
+  let counted = |x: f64| {
+    evaluations.set(evaluations.get() + 1);
+    f(x)
+  };
+  try_bisection(&counted, 0.0, 1.0, 0.25).unwrap();
+  assert_eq!(evaluations.get(), 2 + bisection_iterations(0.0, 1.0, 0.25));
+}
+
+#[test]
+fn test_brent() {
+  for case in TESTS {
+    let f = &case.f();
+
+    let evaluations = Cell::new(0);
+    let counted = |x: f64| {
+      // Never evaluated outside the bracket
+      assert!(case.a <= x && x <= case.b);
+      evaluations.set(evaluations.get() + 1);
+      f(x)
+    };
+
+    let x = brent(&counted, case.a, case.b, f64::EPSILON);
+    assert_abs_diff_eq!(f(x), 0.0, epsilon = 1e-14);
+
+    // Never much slower than bisection
+    assert!(evaluations.get() <= 2 * bisection_iterations(case.a, case.b, f64::EPSILON) + 2);
+  }
+
+  // A function which is NaN or infinite inside the bracket still terminates, within the iteration cap
+  for value in [f64::NAN, f64::INFINITY] {
+    let evaluations = Cell::new(0);
+    let f = |x: f64| {
+      evaluations.set(evaluations.get() + 1);
+      if x == 0.0 {
+        -1.0
+      } else if x == 1.0 {
+        1.0
+      } else {
+        value
+      }
+    };
+
+    brent(&f, 0.0, 1.0, 1e-10);
+    let n = bisection_iterations(0.0, 1.0, 1e-10);
+    assert!(evaluations.get() <= (n + 1).pow(2) + 2);
+  }
+}
+
+#[test]
+fn test_ridders() {
+  for case in TESTS {
+    let f = &case.f();
+
+    let evaluations = Cell::new(0);
+    let counted = |x: f64| {
+      assert!(case.a <= x && x <= case.b);
+      evaluations.set(evaluations.get() + 1);
+      f(x)
+    };
+
+    let x = ridders(&counted, case.a, case.b, f64::EPSILON);
+    assert_abs_diff_eq!(f(x), 0.0, epsilon = 1e-14);
+    assert!(evaluations.get() <= 2 * bisection_iterations(case.a, case.b, f64::EPSILON) + 2);
+  }
+
+  // Known endpoint values are not evaluated again
+  let evaluations = Cell::new(0);
+  let f = |x: f64| {
+    evaluations.set(evaluations.get() + 1);
+    x * x - 2.0
+  };
+  let x = ridders(&f, (0.0, -2.0), (2.0, 2.0), 1e-15);
+  assert_abs_diff_eq!(x, 2f64.sqrt(), epsilon = 1e-15);
+  assert!(evaluations.get() < bisection_iterations(0.0, 2.0, 1e-15) / 2);
+}
+
+#[test]
+fn test_secant_bisection_hybrid() {
+  for case in TESTS {
+    let f = &case.f();
+
+    let evaluations = Cell::new(0);
+    let counted = |x: f64| {
+      evaluations.set(evaluations.get() + 1);
+      f(x)
+    };
+
+    let x = secant_bisection_hybrid(&counted, case.a, case.b, f64::EPSILON);
+    assert_abs_diff_eq!(f(x), 0.0, epsilon = 1e-14);
+    assert!(evaluations.get() <= 2 * bisection_iterations(case.a, case.b, f64::EPSILON) + 2);
+  }
+}
+
+#[test]
+fn test_is_converged() {
+  assert_eq!(bracket_width(2.0, (1.0, 0.5)), 1.0);
+
+  assert!(is_converged(1.0, 1.0 + 1e-12, 1e-10));
+  assert!(!is_converged(1.0, 1.0 + 1e-8, 1e-10));
+  assert!(is_converged(1e6, 1e6 + 1e-6, 1e-10));
+
+  // Twice as wide as the tolerance allows, which ITP would accept but bisection would refine
+  assert!(!is_converged(1.0, 1.0 + 3e-10, 1e-10));
+  let evaluations = Cell::new(0);
+  let f = |x: f64| {
+    evaluations.set(evaluations.get() + 1);
+    x - 1.0 - 1e-10
+  };
+  bisection(&f, 1.0, 1.0 + 3e-10, 1e-10);
+  assert!(evaluations.get() > 1);
+}
+
+#[test]
+fn test_narrow_itp_brackets() {
+  let evaluations = Cell::new(0);
+  let f = |x: f64| {
+    evaluations.set(evaluations.get() + 1);
+    x - 1.0
+  };
+
+  // Brackets already within tolerance yield their midpoint without evaluating f
+  assert_eq!(itp(&f, 1.0, 1.0, 1e-10), 1.0);
+  assert_eq!(itp(&f, 1.0, 1.0 + 1e-12, 1e-10), 1.0 + 5e-13);
+  assert_eq!(evaluations.get(), 0);
+
+  assert_eq!(try_itp(&f, 1.0, 1.0, 1e-10), Err(Error::ZeroWidth));
+  assert_eq!(try_bisection(&f, 1.0, 1.0, 1e-10), Err(Error::ZeroWidth));
+}
+
+#[test]
+fn test_relative_step_tolerance() {
+  // Near x = 1e6, rounding keeps |f| around 1e-4, far above an absolute tolerance of machine epsilon
+  let f = |x: f64| x * x - 1e12;
+  let g = |x: f64| 2.0 * x;
+
+  let report = newtons_method_report(&f, &g, 1.5e6, f64::EPSILON);
+  assert!(report.converged);
+  assert!(report.iterations < 20);
+  assert_abs_diff_eq!(report.root, 1e6, epsilon = 4e6 * f64::EPSILON);
+
+  let report = secant_report(&f, 1.5e6, 1.4e6, f64::EPSILON);
+  assert!(report.converged);
+  assert!(report.iterations < 20);
+  assert_abs_diff_eq!(report.root, 1e6, epsilon = 4e6 * f64::EPSILON);
+}
+
+#[test]
+fn test_robust_root() {
+  use fastrand::Rng;
+
+  // Uniform noise on [-0.01, 0.01], whose standard deviation is 0.01 / sqrt(3)
+  let rng = std::cell::RefCell::new(Rng::with_seed(1234));
+  let evaluations = Cell::new(0);
+  let f = |x: f64| {
+    evaluations.set(evaluations.get() + 1);
+    x - 1.3 + 0.02 * (rng.borrow_mut().f64() - 0.5)
+  };
+  let noise_level = 0.01 / 3.0_f64.sqrt();
+
+  for samples in [1, 16, 256] {
+    evaluations.set(0);
+    let x = robust_root(&f, 0.0, 2.0, noise_level, samples, 1e-15);
+
+    // Within the band where the sign of the averaged function is unreliable
+    let band = 3.0 * noise_level / (samples as f64).sqrt();
+    assert!((x - 1.3).abs() <= 2.0 * band);
+
+    // Stopping at the noise floor, long before the bracket reaches machine precision
+    assert!(evaluations.get() < 20 * samples);
+  }
+
+  // Without noise, the bracket converges as in bisection
+  let x = robust_root(&|x: f64| x - 1.3, 0.0, 2.0, 0.0, 1, 1e-15);
+  assert_abs_diff_eq!(x, 1.3, epsilon = 1e-14);
+}
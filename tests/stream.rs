@@ -0,0 +1,75 @@
+use approx::assert_abs_diff_eq;
+use itertools::Itertools;
+
+use std::f64::consts::PI;
+use uniarity::stream::ZeroCrossingDetector;
+
+#[test]
+fn test_crossings() {
+  // Crossings of sin(2 pi f t) occur every half period, at k / 2f
+  let frequency = 3.0;
+  let dt = 0.01;
+
+  let mut detector = ZeroCrossingDetector::new();
+  let crossings = (1..=200)
+    .filter_map(|i| {
+      let t = i as f64 * dt + 0.003;
+      detector.push(t, (2.0 * PI * frequency * t).sin())
+    })
+    .collect_vec();
+
+  assert_eq!(crossings.len(), 12);
+  for (k, &t) in crossings.iter().enumerate() {
+    assert_abs_diff_eq!(t, (k + 1) as f64 / (2.0 * frequency), epsilon = 1e-6);
+  }
+
+  // Measure the frequency from the spacing of the crossings
+  let period = 2.0 * (crossings[11] - crossings[0]) / 11.0;
+  assert_abs_diff_eq!(1.0 / period, frequency, epsilon = 1e-5);
+}
+
+#[test]
+fn test_linear_before_history() {
+  // With only two samples, the crossing is interpolated linearly
+  let mut detector = ZeroCrossingDetector::new();
+  assert_eq!(detector.push(0.0, -1.0), None);
+  assert_eq!(detector.push(1.0, 3.0), Some(0.25));
+
+  // Once four samples are available, a cubic signal is located exactly
+  let f = |t: f64| (t - 3.3) * (t + 1.0) * (t - 10.0);
+  let mut detector = ZeroCrossingDetector::new();
+  let crossings = (0..6)
+    .filter_map(|i| detector.push(i as f64, f(i as f64)))
+    .collect_vec();
+  assert_eq!(crossings.len(), 1);
+  assert_abs_diff_eq!(crossings[0], 3.3, epsilon = 1e-14);
+}
+
+#[test]
+fn test_zero_samples() {
+  let mut detector = ZeroCrossingDetector::new();
+  let crossings = [
+    (0.0, 1.0),
+    (1.0, 0.0),
+    (2.0, -1.0),
+    (3.0, 0.0),
+    (4.0, -2.0),
+    (5.0, 2.0),
+  ]
+  .into_iter()
+  .filter_map(|(t, value)| detector.push(t, value))
+  .collect_vec();
+
+  // A zero sample is a crossing, and is not reported again by the sample after it
+  assert_eq!(crossings.len(), 3);
+  assert_eq!(crossings[..2], [1.0, 3.0]);
+  assert!(crossings[2] > 4.0 && crossings[2] < 5.0);
+}
+
+#[test]
+#[should_panic]
+fn test_out_of_order() {
+  let mut detector = ZeroCrossingDetector::new();
+  detector.push(1.0, 1.0);
+  detector.push(1.0, -1.0);
+}